@@ -1,7 +1,26 @@
 use reqwest;
 use serde::Deserialize;
 
-use crate::resource_clients::{run::{RunClient}, dataset::DatasetClient};
+use crate::resource_clients::{run::{RunClient}, dataset::DatasetClient, key_value_store::KeyValueStoreClient};
+use crate::resource_clients::key_value_store_collection::KeyValueStoreCollectionClient;
+use crate::resource_clients::dataset_collection::DatasetCollectionClient;
+use crate::resource_clients::request_queue::RequestQueueClient;
+use crate::resource_clients::schedule::ScheduleClient;
+use crate::resource_clients::schedule_collection::ScheduleCollectionClient;
+use crate::resource_clients::store::StoreCollectionClient;
+use crate::resource_clients::user::UserClient;
+use crate::resource_clients::webhook::WebhookClient;
+use crate::resource_clients::webhook_collection::WebhookCollectionClient;
+use crate::resource_clients::webhook_dispatch::WebhookDispatchClient;
+use crate::resource_clients::webhook_dispatch_collection::WebhookDispatchCollectionClient;
+use crate::resource_clients::run_collection::RunCollectionClient;
+use crate::resource_clients::actor::ActorClient;
+use crate::resource_clients::actor_collection::ActorCollectionClient;
+use crate::resource_clients::task::TaskClient;
+use crate::resource_clients::task_collection::TaskCollectionClient;
+use crate::resource_clients::build::BuildClient;
+use crate::resource_clients::build_collection::BuildCollectionClient;
+use crate::resource_clients::log::LogClient;
 
 pub struct ApifyClient {
     // The token is optional
@@ -33,14 +52,100 @@ impl ApifyClient {
         }
     }
 
+    pub fn actor (&self, id_or_name: &str) -> ActorClient {
+        ActorClient::new(self, id_or_name)
+    }
+
+    pub fn actors (&self) -> ActorCollectionClient {
+        ActorCollectionClient::new(self)
+    }
+
+    pub fn task (&self, id_or_name: &str) -> TaskClient {
+        TaskClient::new(self, id_or_name)
+    }
+
+    pub fn tasks (&self) -> TaskCollectionClient {
+        TaskCollectionClient::new(self)
+    }
+
     pub fn run (&self, id_or_name: &str) -> RunClient {
         RunClient::new(self, id_or_name)
     }
 
+    /// All runs of the user
+    pub fn runs (&self) -> RunCollectionClient {
+        RunCollectionClient::new(self)
+    }
+
+    pub fn build (&self, id: &str) -> BuildClient {
+        BuildClient::new(self, id)
+    }
+
+    /// All builds of the user
+    pub fn builds (&self) -> BuildCollectionClient {
+        BuildCollectionClient::new(self)
+    }
+
+    /// Log of a run or a build
+    pub fn log (&self, id: &str) -> LogClient {
+        LogClient::new(self, id)
+    }
+
     pub fn dataset (&self, id_or_name: &str) -> DatasetClient {
         DatasetClient::new(self, id_or_name)
     }
 
+    pub fn datasets (&self) -> DatasetCollectionClient {
+        DatasetCollectionClient::new(self)
+    }
+
+    pub fn key_value_store (&self, id_or_name: &str) -> KeyValueStoreClient {
+        KeyValueStoreClient::new(self, id_or_name)
+    }
+
+    pub fn key_value_stores (&self) -> KeyValueStoreCollectionClient {
+        KeyValueStoreCollectionClient::new(self)
+    }
+
+    pub fn request_queue (&self, id_or_name: &str) -> RequestQueueClient {
+        RequestQueueClient::new(self, id_or_name)
+    }
+
+    pub fn schedule (&self, id: &str) -> ScheduleClient {
+        ScheduleClient::new(self, id)
+    }
+
+    pub fn schedules (&self) -> ScheduleCollectionClient {
+        ScheduleCollectionClient::new(self)
+    }
+
+    /// Public actors in Apify Store
+    pub fn store (&self) -> StoreCollectionClient {
+        StoreCollectionClient::new(self)
+    }
+
+    /// Use `me` for the user of the token
+    pub fn user (&self, id_or_me: &str) -> UserClient {
+        UserClient::new(self, id_or_me)
+    }
+
+    pub fn webhook (&self, id: &str) -> WebhookClient {
+        WebhookClient::new(self, id)
+    }
+
+    pub fn webhooks (&self) -> WebhookCollectionClient {
+        WebhookCollectionClient::new(self)
+    }
+
+    pub fn webhook_dispatch (&self, id: &str) -> WebhookDispatchClient {
+        WebhookDispatchClient::new(self, id)
+    }
+
+    /// All webhook dispatches of the user
+    pub fn webhook_dispatches (&self) -> WebhookDispatchCollectionClient {
+        WebhookDispatchCollectionClient::new(self)
+    }
+
     /// Sets a token on the client
     pub fn token (&mut self, token: String) -> () {
         self.optional_token = Some(token);
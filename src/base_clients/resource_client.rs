@@ -9,19 +9,29 @@ pub trait ResourceClient<'a, T> {
     fn get_client(&self) -> &'a ApifyClient;
     fn get_url_segment(&self) -> &str;
 
+    /// Query params of the resource itself that every request to it has to carry,
+    /// e.g. the status filter of the last run that is kept for its storages and log
+    fn get_query_string(&self) -> String {
+        String::new()
+    }
+
+    /// Request to the resource or to its sub path, always use this so the query params of the resource are not lost
+    fn base_builder<O>(&self, method: reqwest::Method, sub_path: &str) -> BaseBuilder<'a, O> {
+        let url_segment = if sub_path.is_empty() {
+            self.get_url_segment().to_owned()
+        } else {
+            format!("{}/{}", self.get_url_segment(), sub_path)
+        };
+        let mut base_builder = BaseBuilder::new(self.get_client(), url_segment, method);
+        base_builder.append_query_string(self.get_query_string());
+        base_builder
+    }
+
     fn get(&self) -> BaseBuilder<'a, T> {
-        BaseBuilder::new(
-            self.get_client(),
-            self.get_url_segment().to_owned(),
-            reqwest::Method::GET
-        )
+        self.base_builder(reqwest::Method::GET, "")
     }
 
     fn delete(&self) -> BaseBuilder<'a, NoOutput> {
-        BaseBuilder::new(
-            self.get_client(),
-            self.get_url_segment().to_owned(),
-            reqwest::Method::DELETE,
-        )
+        self.base_builder(reqwest::Method::DELETE, "")
     }
 }
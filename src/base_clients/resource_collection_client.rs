@@ -1,15 +1,15 @@
-use crate::generic_types::{BaseBuilder, NoOutput};
+use crate::builders::list::ListBuilder;
 use crate::apify_client::ApifyClient;
 
-pub trait ResourceCollectionClient<'a, T> {
+// T is the type of a single item in the listed PaginationList
+pub trait ResourceCollectionClient<'a, T: serde::de::DeserializeOwned> {
     fn get_client(&self) -> &'a ApifyClient;
     fn get_url_segment(&self) -> &str;
 
-    fn list(&self) -> BaseBuilder<'a, T> {
-        BaseBuilder::new(
+    fn list(&self) -> ListBuilder<'a, T> {
+        ListBuilder::new(
             self.get_client(),
             self.get_url_segment().to_owned(),
-            reqwest::Method::GET
         )
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,67 @@
+use crate::apify_client::ApifyClient;
+use crate::error::ApifyClientError;
+use crate::generic_types::BaseBuilder;
+use crate::resource_clients::build::Build;
+use crate::utils::encode_query_value;
+
+#[derive(Default, QueryParams)]
+#[allow(non_snake_case)]
+pub struct BuildActorParams {
+    // Free text values are already encoded
+    version: String,
+    tag: Option<String>,
+    useCache: Option<bool>,
+    betaPackages: Option<bool>,
+    waitForFinish: Option<u64>,
+}
+
+/// Builds a version of an actor, use `BuildClient::wait_for_finish` to wait for longer builds
+pub struct BuildActorBuilder<'a> {
+    apify_client: &'a ApifyClient,
+    url_segment: String,
+    options: BuildActorParams,
+}
+
+impl <'a> BuildActorBuilder<'a> {
+    pub fn new(apify_client: &'a ApifyClient, url_segment: String, version_number: &str) -> Self {
+        BuildActorBuilder {
+            apify_client,
+            url_segment,
+            options: BuildActorParams {
+                version: encode_query_value(version_number),
+                ..Default::default()
+            },
+        }
+    }
+
+    pub async fn send(&self) -> Result<Build, ApifyClientError> {
+        let mut base_builder: BaseBuilder<'_, Build> = BaseBuilder::new(
+            self.apify_client,
+            self.url_segment.clone(),
+            reqwest::Method::POST,
+        );
+        base_builder.append_query_string(self.options.to_query_params());
+        base_builder.send().await
+    }
+
+    /// Tag applied to the build when it succeeds, e.g. `latest`
+    pub fn tag(& mut self, tag: &str) -> &'_ mut Self {
+        self.options.tag = Some(encode_query_value(tag));
+        self
+    }
+    /// Uses the Docker cache, enabled by default
+    pub fn use_cache(& mut self, use_cache: bool) -> &'_ mut Self {
+        self.options.useCache = Some(use_cache);
+        self
+    }
+    /// Builds with beta versions of the Apify NPM packages
+    pub fn beta_packages(& mut self, beta_packages: bool) -> &'_ mut Self {
+        self.options.betaPackages = Some(beta_packages);
+        self
+    }
+    /// Max 60 seconds, the returned build may still be running after that
+    pub fn wait_for_finish(& mut self, wait_for_finish_secs: u64) -> &'_ mut Self {
+        self.options.waitForFinish = Some(wait_for_finish_secs);
+        self
+    }
+}
@@ -1,7 +1,10 @@
 use crate::resource_clients::dataset::DatasetClient;
 use std::marker::PhantomData;
 use crate::error::ApifyClientError;
-use crate::generic_types::{BaseBuilder, PaginationList, NoPayload};
+use crate::generic_types::{BaseBuilder, PaginationList};
+use crate::builders::list::paginate;
+use crate::base_clients::resource_client::ResourceClient;
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
 
 #[derive(Debug)]
 pub enum Format {
@@ -75,13 +78,31 @@ impl <'a, T: serde::de::DeserializeOwned> GetItemsBuilder<'a, T> {
     }
 
     pub async fn send(self) -> Result<PaginationList<T>, ApifyClientError> {
-        let mut base_builder: BaseBuilder<'_, PaginationList<T>> = BaseBuilder::new(
-            self.dataset_client.apify_client,
-            self.dataset_client.url_segment.clone(),
-            reqwest::Method::GET,
-        );
+        let mut base_builder: BaseBuilder<'_, T> = self.dataset_client.base_builder(reqwest::Method::GET, "items");
         base_builder.append_query_string(self.options.to_query_params());
-        Ok(base_builder.send().await?)
+        // Items are not wrapped in data, pagination info is in headers
+        let resp = base_builder.validate_and_send_request().await?;
+        BaseBuilder::parse_pagination_list(resp).await
+    }
+
+    /// Yields items one by one and fetches the next page only when the previous one is consumed
+    /// `offset` is where the stream starts and `limit` is the max number of all yielded items
+    /// `page_size` of 0 is treated as 1
+    pub fn into_stream(mut self, page_size: u64) -> impl Stream<Item = Result<T, ApifyClientError>> + 'a where T: 'a {
+        let start_offset = self.options.offset.take().unwrap_or(0);
+        let max_items = self.options.limit.take();
+        let query_string = self.options.to_query_params();
+        let dataset_client = self.dataset_client;
+        paginate(start_offset, max_items, page_size, move |offset, limit| {
+            let mut base_builder: BaseBuilder<'a, T> = dataset_client.base_builder(reqwest::Method::GET, "items");
+            base_builder.append_query_string(query_string.clone());
+            base_builder.append_query_string(format!("offset={}&limit={}", offset, limit));
+            async move {
+                // Items are not wrapped in data, pagination info is in headers
+                let resp = base_builder.validate_and_send_request().await?;
+                BaseBuilder::parse_pagination_list(resp).await
+            }
+        })
     }
 
     pub fn clean(& mut self, clean: bool) -> &'_ mut Self {
@@ -154,6 +175,72 @@ impl <'a, T: serde::de::DeserializeOwned> GetItemsBuilder<'a, T> {
     }
 }
 
+/// Downloads all items in chunks with up to `concurrency` requests at once, items keep their order
+/// Chunks are planned from `item_count` of the dataset, which can be stale, so chunks after that
+/// are fetched one by one until a chunk is not full. Zero `concurrency` or `chunk_size` is treated as 1
+pub struct ListAllItemsParallelBuilder<'a, T> {
+    dataset_client: &'a DatasetClient<'a>,
+    concurrency: usize,
+    chunk_size: u64,
+    _phantom: PhantomData<T>,
+}
+
+impl <'a, T: serde::de::DeserializeOwned> ListAllItemsParallelBuilder<'a, T> {
+    pub fn new(dataset_client: &'a DatasetClient<'a>, concurrency: usize, chunk_size: u64) -> Self {
+        // Zero would never download anything
+        ListAllItemsParallelBuilder {
+            dataset_client,
+            concurrency: concurrency.max(1),
+            chunk_size: chunk_size.max(1),
+            _phantom: PhantomData,
+        }
+    }
+
+    pub async fn send(self) -> Result<Vec<T>, ApifyClientError> where T: 'a {
+        self.into_stream().await?.try_collect().await
+    }
+
+    /// Yields items in their original order, later chunks are downloaded while earlier ones are consumed
+    pub async fn into_stream(self) -> Result<impl Stream<Item = Result<T, ApifyClientError>> + 'a, ApifyClientError> where T: 'a {
+        let dataset = self.dataset_client.get().send().await?;
+        let dataset_client = self.dataset_client;
+        let chunk_size = self.chunk_size;
+        let (planned_offsets, remaining_offset) = plan_chunks(dataset.item_count as u64, chunk_size);
+        let planned_chunks = stream::iter(planned_offsets)
+            .map(move |offset| fetch_chunk::<T>(dataset_client, offset, chunk_size))
+            // Unlike buffer_unordered, this returns chunks in the order they were started
+            .buffered(self.concurrency);
+        // Items pushed after item_count was read, the last one is the first chunk that is not full
+        let remaining_chunks = stream::try_unfold(remaining_offset, move |next_offset| async move {
+            let offset = match next_offset {
+                Some(offset) => offset,
+                None => return Ok(None),
+            };
+            let items = fetch_chunk::<T>(dataset_client, offset, chunk_size).await?;
+            let next_offset = if (items.len() as u64) < chunk_size { None } else { offset.checked_add(chunk_size) };
+            Ok::<_, ApifyClientError>(Some((items, next_offset)))
+        });
+        Ok(planned_chunks
+            .chain(remaining_chunks)
+            .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+            .try_flatten())
+    }
+}
+
+/// Offsets of the chunks that cover `item_count` and the offset after the last of them
+/// Offsets that would overflow u64 are left out, there can't be any items there anyway
+pub(crate) fn plan_chunks(item_count: u64, chunk_size: u64) -> (impl Iterator<Item = u64>, Option<u64>) {
+    let chunk_count = item_count.div_ceil(chunk_size);
+    let offsets = (0..chunk_count).map_while(move |chunk_index| chunk_index.checked_mul(chunk_size));
+    (offsets, chunk_count.checked_mul(chunk_size))
+}
+
+async fn fetch_chunk<'a, T: serde::de::DeserializeOwned>(dataset_client: &'a DatasetClient<'a>, offset: u64, limit: u64) -> Result<Vec<T>, ApifyClientError> {
+    let mut items_builder: GetItemsBuilder<'a, T> = GetItemsBuilder::new(dataset_client);
+    items_builder.offset(offset).limit(limit);
+    items_builder.send().await.map(|pagination_list| pagination_list.items)
+}
+
 // TODO: Deduplicate
 pub struct DownloadItemsBuilder<'a> {
     dataset_client: &'a DatasetClient<'a>,
@@ -172,11 +259,7 @@ impl <'a> DownloadItemsBuilder<'a> {
 
     // All formats except XLSX can be converted to a string
     pub async fn send(self) -> Result<Vec<u8>, ApifyClientError> {
-        let mut base_builder: BaseBuilder<'_, Vec<u8>> = BaseBuilder::new(
-            self.dataset_client.apify_client,
-            self.dataset_client.url_segment.clone(),
-            reqwest::Method::GET,
-        );
+        let mut base_builder: BaseBuilder<'_, Vec<u8>> = self.dataset_client.base_builder(reqwest::Method::GET, "items");
         base_builder.append_query_string(self.options.to_query_params());
         base_builder.append_query_string(format!("format={}&attachment=true", self.format));
         let resp = base_builder.validate_and_send_request().await?;
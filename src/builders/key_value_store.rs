@@ -0,0 +1,56 @@
+use crate::resource_clients::key_value_store::{KeyValueStoreClient, KeyValueStoreKey};
+use crate::error::ApifyClientError;
+use crate::utils::encode_query_value;
+use crate::base_clients::resource_client::ResourceClient;
+use crate::generic_types::{BaseBuilder, CursorPaginationList};
+
+#[derive(Default, QueryParams)]
+#[allow(non_snake_case)]
+pub struct ListKeysParams {
+    limit: Option<u64>,
+    exclusiveStartKey: Option<String>,
+}
+
+pub struct ListKeysBuilder<'a> {
+    key_value_store_client: &'a KeyValueStoreClient<'a>,
+    options: ListKeysParams,
+}
+
+impl <'a> ListKeysBuilder<'a> {
+    pub fn new(key_value_store_client: &'a KeyValueStoreClient<'a>) -> Self {
+        ListKeysBuilder {
+            key_value_store_client,
+            options: Default::default(),
+        }
+    }
+
+    pub async fn send(&self) -> Result<CursorPaginationList<KeyValueStoreKey>, ApifyClientError> {
+        let mut base_builder: BaseBuilder<'_, CursorPaginationList<KeyValueStoreKey>> = self.key_value_store_client.base_builder(reqwest::Method::GET, "keys");
+        base_builder.append_query_string(self.options.to_query_params());
+        base_builder.send().await
+    }
+
+    /// Walks all pages until the list is no longer truncated, `limit` is used as a page size
+    pub async fn send_all(mut self) -> Result<Vec<KeyValueStoreKey>, ApifyClientError> {
+        let mut keys = Vec::new();
+        loop {
+            let page = self.send().await?;
+            keys.extend(page.items);
+            match page.next_exclusive_start_key {
+                Some(next_exclusive_start_key) if page.is_truncated => {
+                    self.options.exclusiveStartKey = Some(encode_query_value(&next_exclusive_start_key));
+                }
+                _ => return Ok(keys),
+            }
+        }
+    }
+
+    pub fn limit(& mut self, limit: u64) -> &'_ mut Self {
+        self.options.limit = Some(limit);
+        self
+    }
+    pub fn exclusive_start_key(& mut self, exclusive_start_key: &str) -> &'_ mut Self {
+        self.options.exclusiveStartKey = Some(encode_query_value(exclusive_start_key));
+        self
+    }
+}
@@ -0,0 +1,130 @@
+use crate::apify_client::ApifyClient;
+use crate::error::ApifyClientError;
+use crate::generic_types::{BaseBuilder, PaginationList};
+use futures::stream::{self, Stream, TryStreamExt};
+use std::future::Future;
+use std::marker::PhantomData;
+
+#[derive(Default, QueryParams)]
+#[allow(non_snake_case)]
+pub struct ListParams {
+    pub(crate) offset: Option<u64>,
+    pub(crate) limit: Option<u64>,
+    pub(crate) desc: Option<bool>,
+    // Only for storages
+    pub(crate) unnamed: Option<bool>,
+    // Only for runs
+    pub(crate) status: Option<String>,
+    // Only for actors
+    pub(crate) my: Option<bool>,
+}
+
+/// Lists resources of a collection client
+/// Params that are specific to a single collection are set in its module
+pub struct ListBuilder<'a, T> {
+    client: &'a ApifyClient,
+    url_segment: String,
+    pub(crate) options: ListParams,
+    _phantom: PhantomData<T>,
+}
+
+impl <'a, T: serde::de::DeserializeOwned> ListBuilder<'a, T> {
+    pub fn new(client: &'a ApifyClient, url_segment: String) -> Self {
+        ListBuilder {
+            client,
+            url_segment,
+            options: Default::default(),
+            _phantom: PhantomData,
+        }
+    }
+
+    pub async fn send(&self) -> Result<PaginationList<T>, ApifyClientError> {
+        let mut base_builder: BaseBuilder<'_, PaginationList<T>> = BaseBuilder::new(
+            self.client,
+            self.url_segment.clone(),
+            reqwest::Method::GET,
+        );
+        base_builder.append_query_string(self.options.to_query_params());
+        base_builder.send().await
+    }
+
+    /// Yields items one by one and fetches the next page only when the previous one is consumed
+    /// `offset` is where the stream starts and `limit` is the max number of all yielded items
+    /// `page_size` of 0 is treated as 1
+    pub fn into_stream(mut self, page_size: u64) -> impl Stream<Item = Result<T, ApifyClientError>> + 'a where T: 'a {
+        let start_offset = self.options.offset.take().unwrap_or(0);
+        let max_items = self.options.limit.take();
+        let query_string = self.options.to_query_params();
+        let client = self.client;
+        let url_segment = self.url_segment;
+        paginate(start_offset, max_items, page_size, move |offset, limit| {
+            let mut base_builder: BaseBuilder<'a, PaginationList<T>> = BaseBuilder::new(
+                client,
+                url_segment.clone(),
+                reqwest::Method::GET,
+            );
+            base_builder.append_query_string(query_string.clone());
+            base_builder.append_query_string(format!("offset={}&limit={}", offset, limit));
+            base_builder.send()
+        })
+    }
+
+    pub fn offset(& mut self, offset: u64) -> &'_ mut Self {
+        self.options.offset = Some(offset);
+        self
+    }
+    pub fn limit(& mut self, limit: u64) -> &'_ mut Self {
+        self.options.limit = Some(limit);
+        self
+    }
+    pub fn desc(& mut self, desc: bool) -> &'_ mut Self {
+        self.options.desc = Some(desc);
+        self
+    }
+}
+
+/// Turns offset paginated pages into a stream of items, pages are fetched lazily until `total` is reached
+/// `fetch_page` gets the offset and limit of the page
+pub(crate) fn paginate<'a, T, F, Fut>(
+    start_offset: u64,
+    max_items: Option<u64>,
+    page_size: u64,
+    fetch_page: F,
+) -> impl Stream<Item = Result<T, ApifyClientError>> + 'a
+where
+    T: 'a,
+    F: Fn(u64, u64) -> Fut + 'a,
+    Fut: Future<Output = Result<PaginationList<T>, ApifyClientError>> + 'a,
+{
+    // Zero page size would never move the offset
+    let page_size = page_size.max(1);
+    let initial_state = match max_items {
+        Some(0) => None,
+        _ => Some((start_offset, max_items)),
+    };
+    stream::try_unfold(initial_state, move |state| {
+        let page_request = state.map(|(offset, remaining)| {
+            let limit = remaining.map_or(page_size, |remaining| remaining.min(page_size));
+            (offset, remaining, limit, fetch_page(offset, limit))
+        });
+        async move {
+            let (offset, remaining, limit, page_future) = match page_request {
+                Some(page_request) => page_request,
+                None => return Ok(None),
+            };
+            let page = page_future.await?;
+            // The API can cap the limit, skipped hidden or empty items still move the offset
+            let page_span = page.limit.filter(|api_limit| *api_limit > 0).map_or(limit, |api_limit| api_limit.min(limit));
+            let next_offset = offset + page_span;
+            let remaining = remaining.map(|remaining| remaining.saturating_sub(page_span));
+            let next_state = if next_offset >= page.total || remaining == Some(0) {
+                None
+            } else {
+                Some((next_offset, remaining))
+            };
+            Ok::<_, ApifyClientError>(Some((page.items, next_state)))
+        }
+    })
+    .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+    .try_flatten()
+}
@@ -1 +1,7 @@
-pub mod dataset;
\ No newline at end of file
+pub mod build;
+pub mod dataset;
+pub mod key_value_store;
+pub mod list;
+pub mod request_queue;
+pub mod run;
+pub mod store;
\ No newline at end of file
@@ -0,0 +1,453 @@
+use crate::resource_clients::request_queue::{
+    RequestQueueClient, Request, RequestQueueOperationInfo, RequestQueueHead, RequestIdentifier,
+    BatchOperationResult, ProcessedRequest, UnprocessedRequest, LockedRequestQueueHead, RequestLockInfo,
+};
+use crate::utils::{encode_query_value, encode_path_segment};
+use crate::base_clients::resource_client::ResourceClient;
+use crate::generic_types::NoOutput;
+use crate::error::{ApifyClientError, ClientValidationError};
+use crate::generic_types::BaseBuilder;
+use futures::stream::{self, StreamExt};
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde::de::DeserializeOwned;
+use std::time::Duration;
+use tokio::time::sleep;
+
+#[derive(Default, QueryParams)]
+pub struct RequestParams {
+    forefront: Option<bool>,
+}
+
+pub struct AddRequestBuilder<'a> {
+    request_queue_client: &'a RequestQueueClient<'a>,
+    request: Request,
+    options: RequestParams,
+}
+
+impl <'a> AddRequestBuilder<'a> {
+    pub fn new(request_queue_client: &'a RequestQueueClient<'a>, request: Request) -> Self {
+        AddRequestBuilder {
+            request_queue_client,
+            request,
+            options: Default::default(),
+        }
+    }
+
+    pub async fn send(&self) -> Result<RequestQueueOperationInfo, ApifyClientError> {
+        let mut base_builder: BaseBuilder<'_, RequestQueueOperationInfo> = self.request_queue_client.base_builder(reqwest::Method::POST, "requests");
+        base_builder.append_query_string(self.options.to_query_params());
+        base_builder.raw_payload(serde_json::to_vec(&self.request)?);
+        base_builder.headers(crate::utils::json_content_headers());
+        base_builder.send().await
+    }
+
+    /// Adds the request to the beginning of the queue
+    pub fn forefront(& mut self, forefront: bool) -> &'_ mut Self {
+        self.options.forefront = Some(forefront);
+        self
+    }
+}
+
+pub struct UpdateRequestBuilder<'a> {
+    request_queue_client: &'a RequestQueueClient<'a>,
+    request: Request,
+    options: RequestParams,
+}
+
+impl <'a> UpdateRequestBuilder<'a> {
+    pub fn new(request_queue_client: &'a RequestQueueClient<'a>, request: Request) -> Self {
+        UpdateRequestBuilder {
+            request_queue_client,
+            request,
+            options: Default::default(),
+        }
+    }
+
+    pub async fn send(&self) -> Result<RequestQueueOperationInfo, ApifyClientError> {
+        // The API would only tell us that the route doesn't exist
+        let id = self.request.id.as_ref().ok_or(ClientValidationError::MissingRequestId)?;
+        let mut base_builder: BaseBuilder<'_, RequestQueueOperationInfo> = self.request_queue_client.base_builder(reqwest::Method::PUT, &format!("requests/{}", encode_path_segment(id)));
+        base_builder.append_query_string(self.options.to_query_params());
+        base_builder.raw_payload(serde_json::to_vec(&self.request)?);
+        base_builder.headers(crate::utils::json_content_headers());
+        base_builder.send().await
+    }
+
+    /// Moves the request to the beginning of the queue
+    pub fn forefront(& mut self, forefront: bool) -> &'_ mut Self {
+        self.options.forefront = Some(forefront);
+        self
+    }
+}
+
+#[derive(Default, QueryParams)]
+pub struct ListHeadParams {
+    limit: Option<u64>,
+}
+
+pub struct ListHeadBuilder<'a> {
+    request_queue_client: &'a RequestQueueClient<'a>,
+    options: ListHeadParams,
+}
+
+impl <'a> ListHeadBuilder<'a> {
+    pub fn new(request_queue_client: &'a RequestQueueClient<'a>) -> Self {
+        ListHeadBuilder {
+            request_queue_client,
+            options: Default::default(),
+        }
+    }
+
+    pub async fn send(&self) -> Result<RequestQueueHead, ApifyClientError> {
+        let mut base_builder: BaseBuilder<'_, RequestQueueHead> = self.request_queue_client.base_builder(reqwest::Method::GET, "head");
+        base_builder.append_query_string(self.options.to_query_params());
+        base_builder.send().await
+    }
+
+    pub fn limit(& mut self, limit: u64) -> &'_ mut Self {
+        self.options.limit = Some(limit);
+        self
+    }
+}
+
+#[derive(Default, QueryParams)]
+#[allow(non_snake_case)]
+pub struct LockParams {
+    lockSecs: Option<u64>,
+    limit: Option<u64>,
+    forefront: Option<bool>,
+    clientKey: Option<String>,
+}
+
+pub struct ListAndLockHeadBuilder<'a> {
+    request_queue_client: &'a RequestQueueClient<'a>,
+    options: LockParams,
+}
+
+impl <'a> ListAndLockHeadBuilder<'a> {
+    pub fn new(request_queue_client: &'a RequestQueueClient<'a>, lock_secs: u64, limit: u64) -> Self {
+        ListAndLockHeadBuilder {
+            request_queue_client,
+            options: LockParams {
+                lockSecs: Some(lock_secs),
+                limit: Some(limit),
+                ..Default::default()
+            },
+        }
+    }
+
+    pub async fn send(&self) -> Result<LockedRequestQueueHead, ApifyClientError> {
+        let mut base_builder: BaseBuilder<'_, LockedRequestQueueHead> = self.request_queue_client.base_builder(Method::POST, "head/lock");
+        base_builder.append_query_string(self.options.to_query_params());
+        base_builder.send().await
+    }
+
+    /// Identifies the consumer that holds the locks
+    pub fn client_key(& mut self, client_key: &str) -> &'_ mut Self {
+        self.options.clientKey = Some(encode_query_value(client_key));
+        self
+    }
+}
+
+pub struct ProlongRequestLockBuilder<'a> {
+    request_queue_client: &'a RequestQueueClient<'a>,
+    id: String,
+    options: LockParams,
+}
+
+impl <'a> ProlongRequestLockBuilder<'a> {
+    pub fn new(request_queue_client: &'a RequestQueueClient<'a>, id: &str, lock_secs: u64) -> Self {
+        ProlongRequestLockBuilder {
+            request_queue_client,
+            id: id.to_owned(),
+            options: LockParams {
+                lockSecs: Some(lock_secs),
+                ..Default::default()
+            },
+        }
+    }
+
+    pub async fn send(&self) -> Result<RequestLockInfo, ApifyClientError> {
+        let mut base_builder: BaseBuilder<'_, RequestLockInfo> = self.request_queue_client.base_builder(Method::PUT, &format!("requests/{}/lock", encode_path_segment(&self.id)));
+        base_builder.append_query_string(self.options.to_query_params());
+        base_builder.send().await
+    }
+
+    /// Moves the request to the beginning of the queue once the lock expires
+    pub fn forefront(& mut self, forefront: bool) -> &'_ mut Self {
+        self.options.forefront = Some(forefront);
+        self
+    }
+    /// Must be the same key that locked the request
+    pub fn client_key(& mut self, client_key: &str) -> &'_ mut Self {
+        self.options.clientKey = Some(encode_query_value(client_key));
+        self
+    }
+}
+
+pub struct DeleteRequestLockBuilder<'a> {
+    request_queue_client: &'a RequestQueueClient<'a>,
+    id: String,
+    options: LockParams,
+}
+
+impl <'a> DeleteRequestLockBuilder<'a> {
+    pub fn new(request_queue_client: &'a RequestQueueClient<'a>, id: &str) -> Self {
+        DeleteRequestLockBuilder {
+            request_queue_client,
+            id: id.to_owned(),
+            options: Default::default(),
+        }
+    }
+
+    pub async fn send(&self) -> Result<NoOutput, ApifyClientError> {
+        let mut base_builder: BaseBuilder<'_, NoOutput> = self.request_queue_client.base_builder(Method::DELETE, &format!("requests/{}/lock", encode_path_segment(&self.id)));
+        base_builder.append_query_string(self.options.to_query_params());
+        base_builder.send().await
+    }
+
+    /// Moves the unlocked request to the beginning of the queue
+    pub fn forefront(& mut self, forefront: bool) -> &'_ mut Self {
+        self.options.forefront = Some(forefront);
+        self
+    }
+    /// Must be the same key that locked the request
+    pub fn client_key(& mut self, client_key: &str) -> &'_ mut Self {
+        self.options.clientKey = Some(encode_query_value(client_key));
+        self
+    }
+}
+
+// Max number of requests the API accepts in one batch call
+const BATCH_SIZE: usize = 25;
+const DEFAULT_BATCH_PARALLELISM: usize = 5;
+const DEFAULT_MAX_UNPROCESSED_RETRIES: u8 = 5;
+
+// Lets the batch retry loop find which of the sent items were not processed
+// and report items of failed batch calls as unprocessed
+pub(crate) trait BatchItem {
+    fn is_unprocessed(&self, unprocessed_requests: &[UnprocessedRequest]) -> bool;
+    fn to_unprocessed(&self) -> UnprocessedRequest;
+}
+
+impl BatchItem for Request {
+    fn is_unprocessed(&self, unprocessed_requests: &[UnprocessedRequest]) -> bool {
+        unprocessed_requests.iter().any(|unprocessed| unprocessed.unique_key.as_ref() == Some(&self.unique_key))
+    }
+
+    fn to_unprocessed(&self) -> UnprocessedRequest {
+        UnprocessedRequest {
+            id: self.id.clone(),
+            unique_key: Some(self.unique_key.clone()),
+            url: Some(self.url.clone()),
+            method: self.method.clone(),
+        }
+    }
+}
+
+impl BatchItem for RequestIdentifier {
+    fn is_unprocessed(&self, unprocessed_requests: &[UnprocessedRequest]) -> bool {
+        unprocessed_requests.iter().any(|unprocessed| {
+            (self.id.is_some() && unprocessed.id == self.id)
+                || (self.unique_key.is_some() && unprocessed.unique_key == self.unique_key)
+        })
+    }
+
+    fn to_unprocessed(&self) -> UnprocessedRequest {
+        UnprocessedRequest {
+            id: self.id.clone(),
+            unique_key: self.unique_key.clone(),
+            url: None,
+            method: None,
+        }
+    }
+}
+
+// Response of a single batch call
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BatchCallResult<T> {
+    processed_requests: Vec<T>,
+    unprocessed_requests: Vec<UnprocessedRequest>,
+}
+
+#[derive(Default, QueryParams)]
+pub struct BatchParams {
+    forefront: Option<bool>,
+}
+
+/// Splits items into batches the API accepts, sends them concurrently
+/// and re-submits unprocessed requests with the client's backoff
+struct BatchSender<'a> {
+    request_queue_client: &'a RequestQueueClient<'a>,
+    method: Method,
+    query_string: String,
+    parallelism: usize,
+    max_unprocessed_retries: u8,
+}
+
+impl <'a> BatchSender<'a> {
+    /// Fails only if all batches failed, otherwise errors of failed batches are returned
+    /// in `errors` next to requests that were processed by the other batches
+    async fn send<I, P>(&self, items: &[I]) -> Result<BatchOperationResult<P>, ApifyClientError>
+    where I: Serialize + BatchItem + Clone, P: DeserializeOwned {
+        let chunk_results: Vec<BatchOperationResult<P>> = stream::iter(items.chunks(BATCH_SIZE))
+            .map(|chunk| self.send_chunk(chunk.to_vec()))
+            // Zero would never poll any batch
+            .buffer_unordered(self.parallelism.max(1))
+            .collect()
+            .await;
+
+        if !chunk_results.is_empty() && chunk_results.iter().all(|chunk_result| !chunk_result.errors.is_empty()) {
+            let first_error = chunk_results.into_iter().flat_map(|chunk_result| chunk_result.errors).next();
+            return Err(first_error.unwrap());
+        }
+        let mut batch_result = BatchOperationResult {
+            processed_requests: Vec::new(),
+            unprocessed_requests: Vec::new(),
+            errors: Vec::new(),
+        };
+        for chunk_result in chunk_results {
+            batch_result.processed_requests.extend(chunk_result.processed_requests);
+            batch_result.unprocessed_requests.extend(chunk_result.unprocessed_requests);
+            batch_result.errors.extend(chunk_result.errors);
+        }
+        Ok(batch_result)
+    }
+
+    async fn send_chunk<I, P>(&self, mut chunk: Vec<I>) -> BatchOperationResult<P>
+    where I: Serialize + BatchItem, P: DeserializeOwned {
+        let mut processed_requests = Vec::new();
+        let mut retry_count: u8 = 0;
+        loop {
+            let chunk_result = match self.send_chunk_once(&chunk).await {
+                Ok(chunk_result) => chunk_result,
+                Err(err) => {
+                    // Requests processed by earlier retries are kept, the rest is reported as unprocessed
+                    return BatchOperationResult {
+                        processed_requests,
+                        unprocessed_requests: chunk.iter().map(BatchItem::to_unprocessed).collect(),
+                        errors: vec![err],
+                    };
+                }
+            };
+            processed_requests.extend(chunk_result.processed_requests);
+            let unprocessed_requests = chunk_result.unprocessed_requests;
+
+            if unprocessed_requests.is_empty() || retry_count >= self.max_unprocessed_retries {
+                return BatchOperationResult {
+                    processed_requests,
+                    unprocessed_requests,
+                    errors: Vec::new(),
+                };
+            }
+            chunk.retain(|item| item.is_unprocessed(&unprocessed_requests));
+
+            retry_count += 1;
+            let time_to_next_retry = self.request_queue_client.apify_client.get_time_to_next_retry(retry_count);
+            if self.request_queue_client.apify_client.debug_log {
+                println!("{} requests were not processed, retry n. {} will happen in: {} ms", chunk.len(), retry_count, time_to_next_retry);
+            }
+            sleep(Duration::from_millis(time_to_next_retry.into())).await;
+        }
+    }
+
+    async fn send_chunk_once<I, P>(&self, chunk: &[I]) -> Result<BatchCallResult<P>, ApifyClientError>
+    where I: Serialize, P: DeserializeOwned {
+        let mut base_builder: BaseBuilder<'_, BatchCallResult<P>> = self.request_queue_client.base_builder(self.method.clone(), "requests/batch");
+        base_builder.append_query_string(self.query_string.clone());
+        base_builder.raw_payload(serde_json::to_vec(chunk)?);
+        base_builder.headers(crate::utils::json_content_headers());
+        base_builder.send().await
+    }
+}
+
+pub struct BatchAddRequestsBuilder<'a> {
+    request_queue_client: &'a RequestQueueClient<'a>,
+    requests: Vec<Request>,
+    options: BatchParams,
+    parallelism: usize,
+    max_unprocessed_retries: u8,
+}
+
+impl <'a> BatchAddRequestsBuilder<'a> {
+    pub fn new(request_queue_client: &'a RequestQueueClient<'a>, requests: Vec<Request>) -> Self {
+        BatchAddRequestsBuilder {
+            request_queue_client,
+            requests,
+            options: Default::default(),
+            parallelism: DEFAULT_BATCH_PARALLELISM,
+            max_unprocessed_retries: DEFAULT_MAX_UNPROCESSED_RETRIES,
+        }
+    }
+
+    /// Requests that stay unprocessed after all retries are returned in `unprocessed_requests`
+    /// Fails if no batch call succeeded, otherwise errors of failed batch calls are in `errors`
+    pub async fn send(&self) -> Result<BatchOperationResult<ProcessedRequest>, ApifyClientError> {
+        let batch_sender = BatchSender {
+            request_queue_client: self.request_queue_client,
+            method: Method::POST,
+            query_string: self.options.to_query_params(),
+            parallelism: self.parallelism,
+            max_unprocessed_retries: self.max_unprocessed_retries,
+        };
+        batch_sender.send(&self.requests).await
+    }
+
+    /// Adds the requests to the beginning of the queue
+    pub fn forefront(& mut self, forefront: bool) -> &'_ mut Self {
+        self.options.forefront = Some(forefront);
+        self
+    }
+    /// How many batches of 25 requests are sent at the same time, 0 is treated as 1
+    pub fn parallelism(& mut self, parallelism: usize) -> &'_ mut Self {
+        self.parallelism = parallelism;
+        self
+    }
+    pub fn max_unprocessed_retries(& mut self, max_unprocessed_retries: u8) -> &'_ mut Self {
+        self.max_unprocessed_retries = max_unprocessed_retries;
+        self
+    }
+}
+
+pub struct BatchDeleteRequestsBuilder<'a> {
+    request_queue_client: &'a RequestQueueClient<'a>,
+    requests: Vec<RequestIdentifier>,
+    parallelism: usize,
+    max_unprocessed_retries: u8,
+}
+
+impl <'a> BatchDeleteRequestsBuilder<'a> {
+    pub fn new(request_queue_client: &'a RequestQueueClient<'a>, requests: Vec<RequestIdentifier>) -> Self {
+        BatchDeleteRequestsBuilder {
+            request_queue_client,
+            requests,
+            parallelism: DEFAULT_BATCH_PARALLELISM,
+            max_unprocessed_retries: DEFAULT_MAX_UNPROCESSED_RETRIES,
+        }
+    }
+
+    /// Requests that stay unprocessed after all retries are returned in `unprocessed_requests`
+    /// Fails if no batch call succeeded, otherwise errors of failed batch calls are in `errors`
+    pub async fn send(&self) -> Result<BatchOperationResult<RequestIdentifier>, ApifyClientError> {
+        let batch_sender = BatchSender {
+            request_queue_client: self.request_queue_client,
+            method: Method::DELETE,
+            query_string: String::new(),
+            parallelism: self.parallelism,
+            max_unprocessed_retries: self.max_unprocessed_retries,
+        };
+        batch_sender.send(&self.requests).await
+    }
+
+    /// How many batches of 25 requests are sent at the same time, 0 is treated as 1
+    pub fn parallelism(& mut self, parallelism: usize) -> &'_ mut Self {
+        self.parallelism = parallelism;
+        self
+    }
+    pub fn max_unprocessed_retries(& mut self, max_unprocessed_retries: u8) -> &'_ mut Self {
+        self.max_unprocessed_retries = max_unprocessed_retries;
+        self
+    }
+}
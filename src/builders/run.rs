@@ -0,0 +1,314 @@
+use crate::apify_client::ApifyClient;
+use crate::error::ApifyClientError;
+use crate::utils::{content_type_headers, encode_query_value};
+use crate::generic_types::BaseBuilder;
+use crate::base_clients::resource_client::ResourceClient;
+use crate::resource_clients::run::{Run, RunClient};
+use crate::resource_clients::webhook::WebhookEventType;
+use serde::Serialize;
+use std::marker::PhantomData;
+use std::time::{Duration, Instant};
+
+// Max time the API holds the request open with waitForFinish
+const MAX_WAIT_FOR_FINISH_SECS: u64 = 60;
+
+/// Webhook that is only attached to the started run
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AdHocWebhook {
+    pub event_types: Vec<WebhookEventType>,
+    pub request_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload_template: Option<String>,
+}
+
+#[derive(Default, QueryParams)]
+#[allow(non_snake_case)]
+pub struct StartRunParams {
+    build: Option<String>,
+    memory: Option<u32>,
+    timeout: Option<u64>,
+    waitForFinish: Option<u64>,
+}
+
+/// Starts a run of an actor, `call` variant also waits until the run finishes
+pub struct StartRunBuilder<'a> {
+    apify_client: &'a ApifyClient,
+    url_segment: String,
+    input: Option<Vec<u8>>,
+    content_type: Option<String>,
+    webhooks: Option<Vec<AdHocWebhook>>,
+    options: StartRunParams,
+    wait_until_finished: bool,
+}
+
+impl <'a> StartRunBuilder<'a> {
+    pub fn new(apify_client: &'a ApifyClient, url_segment: String, wait_until_finished: bool) -> Self {
+        StartRunBuilder {
+            apify_client,
+            url_segment,
+            input: None,
+            content_type: None,
+            webhooks: None,
+            options: Default::default(),
+            wait_until_finished,
+        }
+    }
+
+    pub async fn send(&self) -> Result<Run, ApifyClientError> {
+        let mut base_builder: BaseBuilder<'_, Run> = BaseBuilder::new(
+            self.apify_client,
+            self.url_segment.clone(),
+            reqwest::Method::POST,
+        );
+        base_builder.append_query_string(self.options.to_query_params());
+        if let Some(webhooks) = &self.webhooks {
+            // Base64 encoded JSON array, base64 uses URL reserved chars
+            let webhooks_base64 = base64::encode(serde_json::to_vec(webhooks)?);
+            base_builder.append_query_string(format!("webhooks={}", encode_query_value(&webhooks_base64)));
+        }
+        if let Some(input) = &self.input {
+            base_builder.raw_payload(input.clone());
+        }
+        if let Some(content_type) = &self.content_type {
+            base_builder.headers(content_type_headers(content_type)?);
+        }
+        let run = base_builder.send().await?;
+        if !self.wait_until_finished {
+            return Ok(run);
+        }
+
+        RunClient::new(self.apify_client, &run.id).wait_for_finish(None).send().await
+    }
+
+    /// JSON input of the run
+    pub fn input(& mut self, input: serde_json::Value) -> &'_ mut Self {
+        self.input = Some(input.to_string().into_bytes());
+        self.content_type = Some("application/json; charset=utf-8".to_owned());
+        self
+    }
+    /// Input with any content type, e.g. binary data
+    pub fn raw_input(& mut self, input: Vec<u8>, content_type: &str) -> &'_ mut Self {
+        self.input = Some(input);
+        self.content_type = Some(content_type.to_owned());
+        self
+    }
+    /// Tag or number of the build to run
+    pub fn build(& mut self, build: &str) -> &'_ mut Self {
+        self.options.build = Some(build.to_owned());
+        self
+    }
+    pub fn memory(& mut self, memory_mbytes: u32) -> &'_ mut Self {
+        self.options.memory = Some(memory_mbytes);
+        self
+    }
+    pub fn timeout(& mut self, timeout_secs: u64) -> &'_ mut Self {
+        self.options.timeout = Some(timeout_secs);
+        self
+    }
+    /// Max 60 seconds, the returned run may still be running after that
+    pub fn wait_for_finish(& mut self, wait_for_finish_secs: u64) -> &'_ mut Self {
+        self.options.waitForFinish = Some(wait_for_finish_secs);
+        self
+    }
+    pub fn webhooks(& mut self, webhooks: Vec<AdHocWebhook>) -> &'_ mut Self {
+        self.webhooks = Some(webhooks);
+        self
+    }
+}
+
+#[derive(Default, QueryParams)]
+pub struct AbortRunParams {
+    gracefully: Option<bool>,
+}
+
+pub struct AbortRunBuilder<'a> {
+    run_client: &'a RunClient<'a>,
+    options: AbortRunParams,
+}
+
+impl <'a> AbortRunBuilder<'a> {
+    pub fn new(run_client: &'a RunClient<'a>) -> Self {
+        AbortRunBuilder {
+            run_client,
+            options: Default::default(),
+        }
+    }
+
+    pub async fn send(&self) -> Result<Run, ApifyClientError> {
+        let mut base_builder: BaseBuilder<'_, Run> = self.run_client.base_builder(reqwest::Method::POST, "abort");
+        base_builder.append_query_string(self.options.to_query_params());
+        base_builder.send().await
+    }
+
+    /// Lets the actor persist its state before the run is aborted
+    pub fn gracefully(& mut self, gracefully: bool) -> &'_ mut Self {
+        self.options.gracefully = Some(gracefully);
+        self
+    }
+}
+
+#[derive(Default, QueryParams)]
+pub struct ResurrectRunParams {
+    build: Option<String>,
+    memory: Option<u32>,
+    timeout: Option<u64>,
+}
+
+pub struct ResurrectRunBuilder<'a> {
+    run_client: &'a RunClient<'a>,
+    options: ResurrectRunParams,
+}
+
+impl <'a> ResurrectRunBuilder<'a> {
+    pub fn new(run_client: &'a RunClient<'a>) -> Self {
+        ResurrectRunBuilder {
+            run_client,
+            options: Default::default(),
+        }
+    }
+
+    pub async fn send(&self) -> Result<Run, ApifyClientError> {
+        let mut base_builder: BaseBuilder<'_, Run> = self.run_client.base_builder(reqwest::Method::POST, "resurrect");
+        base_builder.append_query_string(self.options.to_query_params());
+        base_builder.send().await
+    }
+
+    /// Tag or number of the build to run, defaults to the build of the original run
+    pub fn build(& mut self, build: &str) -> &'_ mut Self {
+        self.options.build = Some(build.to_owned());
+        self
+    }
+    pub fn memory(& mut self, memory_mbytes: u32) -> &'_ mut Self {
+        self.options.memory = Some(memory_mbytes);
+        self
+    }
+    pub fn timeout(& mut self, timeout_secs: u64) -> &'_ mut Self {
+        self.options.timeout = Some(timeout_secs);
+        self
+    }
+}
+
+#[derive(Default, QueryParams)]
+#[allow(non_snake_case)]
+pub struct MetamorphRunParams {
+    targetActorId: Option<String>,
+    build: Option<String>,
+}
+
+pub struct MetamorphRunBuilder<'a> {
+    run_client: &'a RunClient<'a>,
+    input: Option<Vec<u8>>,
+    content_type: Option<String>,
+    options: MetamorphRunParams,
+}
+
+impl <'a> MetamorphRunBuilder<'a> {
+    pub fn new(run_client: &'a RunClient<'a>, target_actor_id: &str) -> Self {
+        MetamorphRunBuilder {
+            run_client,
+            input: None,
+            content_type: None,
+            options: MetamorphRunParams {
+                targetActorId: Some(target_actor_id.to_owned()),
+                build: None,
+            },
+        }
+    }
+
+    pub async fn send(&self) -> Result<Run, ApifyClientError> {
+        let mut base_builder: BaseBuilder<'_, Run> = self.run_client.base_builder(reqwest::Method::POST, "metamorph");
+        base_builder.append_query_string(self.options.to_query_params());
+        if let Some(input) = &self.input {
+            base_builder.raw_payload(input.clone());
+        }
+        if let Some(content_type) = &self.content_type {
+            base_builder.headers(content_type_headers(content_type)?);
+        }
+        base_builder.send().await
+    }
+
+    /// JSON input of the target actor
+    pub fn input(& mut self, input: serde_json::Value) -> &'_ mut Self {
+        self.input = Some(input.to_string().into_bytes());
+        self.content_type = Some("application/json; charset=utf-8".to_owned());
+        self
+    }
+    /// Input with any content type, e.g. binary data
+    pub fn raw_input(& mut self, input: Vec<u8>, content_type: &str) -> &'_ mut Self {
+        self.input = Some(input);
+        self.content_type = Some(content_type.to_owned());
+        self
+    }
+    /// Tag or number of the target actor build
+    pub fn build(& mut self, build: &str) -> &'_ mut Self {
+        self.options.build = Some(build.to_owned());
+        self
+    }
+}
+
+/// Run or build, both can be waited for with the API's waitForFinish
+pub trait ActorJob {
+    /// Job in a terminal status will not change its status anymore
+    fn is_terminal(&self) -> bool;
+}
+
+impl ActorJob for Run {
+    fn is_terminal(&self) -> bool {
+        self.status.is_terminal()
+    }
+}
+
+/// Polls the run or build with the API's waitForFinish until it finishes or `max_wait` passes
+pub struct WaitForFinishBuilder<'a, T> {
+    apify_client: &'a ApifyClient,
+    url_segment: String,
+    // Query params of the run or build, see ResourceClient::get_query_string
+    query_string: String,
+    max_wait: Option<Duration>,
+    _phantom: PhantomData<T>,
+}
+
+impl <'a, T: ActorJob + serde::de::DeserializeOwned> WaitForFinishBuilder<'a, T> {
+    pub fn new(apify_client: &'a ApifyClient, url_segment: String, query_string: String, max_wait: Option<Duration>) -> Self {
+        WaitForFinishBuilder {
+            apify_client,
+            url_segment,
+            query_string,
+            max_wait,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the last fetched job, it is still running if `max_wait` passed before it finished
+    pub async fn send(&self) -> Result<T, ApifyClientError> {
+        let started_at = Instant::now();
+        loop {
+            let wait_for_finish_secs = match self.max_wait {
+                Some(max_wait) => {
+                    let remaining = max_wait.saturating_sub(started_at.elapsed());
+                    // Rounded up, otherwise less than a second left would poll with waitForFinish=0 in a loop
+                    let remaining_secs = remaining.as_secs() + if remaining.subsec_nanos() > 0 { 1 } else { 0 };
+                    remaining_secs.min(MAX_WAIT_FOR_FINISH_SECS)
+                }
+                None => MAX_WAIT_FOR_FINISH_SECS,
+            };
+            let mut base_builder: BaseBuilder<'_, T> = BaseBuilder::new(
+                self.apify_client,
+                self.url_segment.clone(),
+                reqwest::Method::GET,
+            );
+            base_builder.append_query_string(self.query_string.clone());
+            base_builder.append_query_string(format!("waitForFinish={}", wait_for_finish_secs));
+            let job = base_builder.send().await?;
+
+            let deadline_passed = match self.max_wait {
+                Some(max_wait) => started_at.elapsed() >= max_wait,
+                None => false,
+            };
+            if job.is_terminal() || deadline_passed {
+                return Ok(job);
+            }
+        }
+    }
+}
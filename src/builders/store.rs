@@ -0,0 +1,98 @@
+use crate::apify_client::ApifyClient;
+use crate::error::ApifyClientError;
+use crate::generic_types::{BaseBuilder, PaginationList};
+use crate::builders::list::paginate;
+use crate::resource_clients::store::{StoreActor, StoreSortBy, PricingModel};
+use crate::utils::encode_query_value;
+use futures::stream::Stream;
+
+#[derive(Default, QueryParams)]
+#[allow(non_snake_case)]
+pub struct StoreListParams {
+    offset: Option<u64>,
+    limit: Option<u64>,
+    // Free text values are already encoded
+    search: Option<String>,
+    sortBy: Option<String>,
+    category: Option<String>,
+    username: Option<String>,
+    pricingModel: Option<String>,
+}
+
+/// Lists public actors in Apify Store
+pub struct StoreListBuilder<'a> {
+    apify_client: &'a ApifyClient,
+    url_segment: String,
+    options: StoreListParams,
+}
+
+impl <'a> StoreListBuilder<'a> {
+    pub fn new(apify_client: &'a ApifyClient, url_segment: String) -> Self {
+        StoreListBuilder {
+            apify_client,
+            url_segment,
+            options: Default::default(),
+        }
+    }
+
+    pub async fn send(&self) -> Result<PaginationList<StoreActor>, ApifyClientError> {
+        let mut base_builder: BaseBuilder<'_, PaginationList<StoreActor>> = BaseBuilder::new(
+            self.apify_client,
+            self.url_segment.clone(),
+            reqwest::Method::GET,
+        );
+        base_builder.append_query_string(self.options.to_query_params());
+        base_builder.send().await
+    }
+
+    /// Same as `ListBuilder::into_stream`, the store filters apply to every page
+    pub fn into_stream(mut self, page_size: u64) -> impl Stream<Item = Result<StoreActor, ApifyClientError>> + 'a {
+        let start_offset = self.options.offset.take().unwrap_or(0);
+        let max_items = self.options.limit.take();
+        let query_string = self.options.to_query_params();
+        let apify_client = self.apify_client;
+        let url_segment = self.url_segment;
+        paginate(start_offset, max_items, page_size, move |offset, limit| {
+            let mut base_builder: BaseBuilder<'a, PaginationList<StoreActor>> = BaseBuilder::new(
+                apify_client,
+                url_segment.clone(),
+                reqwest::Method::GET,
+            );
+            base_builder.append_query_string(query_string.clone());
+            base_builder.append_query_string(format!("offset={}&limit={}", offset, limit));
+            base_builder.send()
+        })
+    }
+
+    pub fn offset(& mut self, offset: u64) -> &'_ mut Self {
+        self.options.offset = Some(offset);
+        self
+    }
+    pub fn limit(& mut self, limit: u64) -> &'_ mut Self {
+        self.options.limit = Some(limit);
+        self
+    }
+    /// Full text search in title, name, description, username and readme
+    pub fn search(& mut self, search: &str) -> &'_ mut Self {
+        self.options.search = Some(encode_query_value(search));
+        self
+    }
+    pub fn sort_by(& mut self, sort_by: StoreSortBy) -> &'_ mut Self {
+        self.options.sortBy = Some(sort_by.to_string());
+        self
+    }
+    /// E.g. `ECOMMERCE`
+    pub fn category(& mut self, category: &str) -> &'_ mut Self {
+        self.options.category = Some(encode_query_value(category));
+        self
+    }
+    /// Only actors of this user
+    pub fn username(& mut self, username: &str) -> &'_ mut Self {
+        self.options.username = Some(encode_query_value(username));
+        self
+    }
+    pub fn pricing_model(& mut self, pricing_model: PricingModel) -> &'_ mut Self {
+        self.options.pricingModel = Some(encode_query_value(&pricing_model.to_string()));
+        self
+    }
+}
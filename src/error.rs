@@ -32,6 +32,9 @@ pub enum ClientValidationError {
     // Too annoying to check
     // MissingToken,
     InvalidResourceIdOrName(String),
+    /// Only requests that were already added to a queue have an id
+    MissingRequestId,
+    InvalidContentType(String),
 }
 
 impl Display for ClientValidationError {
@@ -26,11 +26,31 @@ pub struct PaginationList<T> {
     pub items: Vec<T>
 }
 
+/// Used by endpoints that always return all items at once
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct UnpaginatedList<T> {
+    pub total: u64,
+    pub items: Vec<T>
+}
+
+/// Used by endpoints that paginate with a key of the last item instead of offset
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CursorPaginationList<T> {
+    pub count: u64,
+    pub limit: u64,
+    pub exclusive_start_key: Option<String>,
+    pub is_truncated: bool,
+    pub next_exclusive_start_key: Option<String>,
+    pub items: Vec<T>
+}
+
 pub struct BaseBuilder <'a, OutputType> {
     client: &'a ApifyClient,
     url_segment: String,
     method: reqwest::Method,
     body: Option<Vec<u8>>,
+    headers: HeaderMap,
     query_string: Option<String>,
     phantom: PhantomData<OutputType>,
 }
@@ -43,6 +63,7 @@ impl <'a, OutputType> BaseBuilder<'a, OutputType> {
             url_segment,
             method,
             body: None,
+            headers: HeaderMap::new(),
             query_string: None,
             phantom: PhantomData,
         }
@@ -53,8 +74,18 @@ impl <'a, OutputType> BaseBuilder<'a, OutputType> {
         self
     }
 
+    pub fn headers(& mut self, headers: HeaderMap) -> &'_ mut Self {
+        self.headers.extend(headers);
+        self
+    }
+
     // TODO: This proc macro crate only converts to string so adding new params is ugly
     pub fn append_query_string(& mut self, append_query_params: String) -> &'_ mut Self {
+        // Output of the QueryParams derive is prefixed with "?" and is empty when no param is set
+        let append_query_params = append_query_params.trim_start_matches('?').to_owned();
+        if append_query_params.is_empty() {
+            return self;
+        }
         if let Some(ref mut existing_query_string) = self.query_string {
             existing_query_string.push('&');
             existing_query_string.push_str(&append_query_params);
@@ -74,7 +105,7 @@ impl <'a, OutputType> BaseBuilder<'a, OutputType> {
         // println!("size of: {}", std::mem::size_of::<T>());
         let body = self.body;
 
-        let resp = self.client.retrying_request(&url, &self.method, &body, &Some(HeaderMap::new())).await?;
+        let resp = self.client.retrying_request(&url, &self.method, &body, &Some(self.headers)).await?;
         Ok(resp)
     }
 }
@@ -89,15 +120,14 @@ impl<'a, T: serde::de::DeserializeOwned> BaseBuilder<'a, T> {
         Ok(apify_client_result.data) 
     }
 
-    pub async fn parse_pagination_list(self, resp: Response) -> Result<PaginationList<T>, ApifyClientError> {
+    pub async fn parse_pagination_list(resp: Response) -> Result<PaginationList<T>, ApifyClientError> {
         // For this endpoint, we have to reconstruct PaginationList manually
         let headers = resp.headers().clone();
         let bytes = resp.bytes().await.map_err(
             |err| ApifyApiError::ApiFailure(format!("Apify API did not return bytes. Something is very wrong. Please contact support@apify.com\n{}", err))
         )?;
         let items: Vec<T> = serde_json::from_slice(&bytes)?;
-        println!("{:?}", headers);
-        
+
         let total: u64 = parse_pagination_header(&headers, "X-Apify-Pagination-Total")?;
         let limit: u64 = parse_pagination_header(&headers, "X-Apify-Pagination-Limit")?;
         let offset: u64 = parse_pagination_header(&headers, "X-Apify-Pagination-Offset")?;
@@ -21,7 +21,16 @@ pub struct ApifyApiErrorRawWrapper {
     error: ApifyApiErrorRaw
 }
 
+// Exponential backoff would overflow with many retries, 10 minutes is already more than enough
+const MAX_TIME_TO_RETRY_MS: u32 = 10 * 60 * 1000;
+
 impl ApifyClient {
+    /// Exponential backoff shared by all retries, starting at `base_time_to_retry` and capped at 10 minutes
+    pub fn get_time_to_next_retry (&self, retry_count: u8) -> u32 {
+        let multiplier = 2_u32.checked_pow(retry_count.into()).unwrap_or(u32::MAX);
+        self.base_time_to_retry.saturating_mul(multiplier).min(MAX_TIME_TO_RETRY_MS)
+    }
+
     async fn simple_request (
         &self,
         url: &str,
@@ -85,14 +94,13 @@ impl ApifyClient {
                         let time_to_next_retry;
                         if status_code == 429 {
                             rate_limit_retry_count += 1;
-                            // TODO: export this as separate func
-                            time_to_next_retry = self.base_time_to_retry * (2 as u32).pow((rate_limit_retry_count).into());
+                            time_to_next_retry = self.get_time_to_next_retry(rate_limit_retry_count);
                             if self.debug_log {
                                 println!("Request got rate limit(429), retry n. will happen {} in: {} ms", rate_limit_retry_count, time_to_next_retry);
                             }
                         } else {
                             server_failed_retry_count += 1;
-                            time_to_next_retry = self.base_time_to_retry * (2 as u32).pow((server_failed_retry_count).into());
+                            time_to_next_retry = self.get_time_to_next_retry(server_failed_retry_count);
                             if self.debug_log {
                                 println!("Server failed({}), retry n. will happen {} in: {} ms", status_code, rate_limit_retry_count, time_to_next_retry);
                             }
@@ -118,7 +126,7 @@ impl ApifyClient {
                 Err(err) => {
                     if err.is_timeout() {
                         timeout_retry_count += 1;
-                        let time_to_next_retry = self.base_time_to_retry * (2 as u32).pow((timeout_retry_count).into());
+                        let time_to_next_retry = self.get_time_to_next_retry(timeout_retry_count);
                         if self.debug_log {
                             println!("Request timeouted, retry n. will happen {} in: {} ms", rate_limit_retry_count, time_to_next_retry);
                         }
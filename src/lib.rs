@@ -20,12 +20,26 @@ pub mod builders;
 #[cfg(test)]
 mod test {
     use super::apify_client::ApifyClient;
-    use super::error::{ApifyApiError, ApifyClientError};
+    use super::error::{ApifyApiError, ApifyClientError, ClientValidationError};
     use super::generic_types::{NoOutput, PaginationList};
     use serde::{Serialize, Deserialize};
-    use super::resource_clients::run::Run;
+    use futures::stream::TryStreamExt;
+    use super::resource_clients::run::{Run, RunStatus};
+    use super::builders::run::ActorJob;
     use super::resource_clients::dataset::Dataset;
+    use super::resource_clients::key_value_store::{KeyValueStore, KeyValueStoreRecord, RecordValue, KeyValueStoreKey};
+    use super::base_clients::resource_collection_client::ResourceCollectionClient;
+    use super::resource_clients::request_queue::{RequestQueue, Request, RequestIdentifier};
+    use super::generic_types::BaseBuilder;
     use super::base_clients::resource_client::ResourceClient;
+    use super::resource_clients::task::{TaskDefinition, TaskOptions};
+    use super::resource_clients::actor_version::{ActorVersion, ActorVersionUpdate, ActorVersionSource, ActorSourceFile, ActorSourceFileFormat};
+    use super::resource_clients::actor_env_var::ActorEnvVar;
+    use super::resource_clients::actor::{ActorDefinition, ActorDefaultRunOptions};
+    use super::resource_clients::schedule::{ScheduleDefinition, ScheduleAction, ScheduleRunInput, ScheduleRunOptions};
+    use super::resource_clients::webhook::{WebhookDefinition, WebhookCondition, WebhookEventType};
+    use super::resource_clients::user::{ServiceUsage, LimitsUpdate};
+    use super::resource_clients::store::{StoreSortBy, PricingModel};
 
     // Simple await macro for tests
     macro_rules! await_test {
@@ -54,9 +68,8 @@ mod test {
 
     
     fn create_dataset (client: &ApifyClient, name: &str) -> Dataset {
-        unimplemented!();
-        //let dataset = await_test!(client.create_dataset(name).send()).unwrap();
-        //dataset
+        let dataset = await_test!(client.datasets().get_or_create(name).send()).unwrap();
+        dataset
     }
 
     fn update_dataset (client: &ApifyClient, id_or_name: &str, name: &str) -> Dataset {
@@ -88,6 +101,52 @@ mod test {
     }
     
 
+    fn create_key_value_store (client: &ApifyClient, name: &str) -> KeyValueStore {
+        let store = await_test!(client.key_value_stores().get_or_create(name).send()).unwrap();
+        store
+    }
+
+    fn list_key_value_stores (client: &ApifyClient) -> Result<PaginationList<KeyValueStore>, ApifyClientError> {
+        let maybe_pagination_list = await_test!(client.key_value_stores().list().desc(true).limit(10).send());
+        maybe_pagination_list
+    }
+
+    fn delete_key_value_store (client: &ApifyClient, id_or_name: &str) -> NoOutput {
+        let no_content = await_test!(client.key_value_store(id_or_name).delete().send()).unwrap();
+        no_content
+    }
+
+    fn set_record<T: Serialize> (client: &ApifyClient, id_or_name: &str, key: &str, value: RecordValue<T>) -> Result<NoOutput, ApifyClientError> {
+        let set_result = await_test!(client.key_value_store(id_or_name).set_record(key, value).send());
+        set_result
+    }
+
+    fn get_record (client: &ApifyClient, id_or_name: &str, key: &str) -> Result<KeyValueStoreRecord<Item>, ApifyClientError> {
+        let maybe_record = await_test!(client.key_value_store(id_or_name).get_record(key).send());
+        maybe_record
+    }
+
+    fn list_all_keys (client: &ApifyClient, id_or_name: &str, page_size: u64) -> Result<Vec<KeyValueStoreKey>, ApifyClientError> {
+        let store_client = client.key_value_store(id_or_name);
+        let mut builder = store_client.list_keys();
+        builder.limit(page_size);
+        let maybe_keys = await_test!(builder.send_all());
+        maybe_keys
+    }
+
+    // TODO: Replace with collection client once we have it
+    fn create_request_queue (client: &ApifyClient, name: &str) -> RequestQueue {
+        let mut builder: BaseBuilder<'_, RequestQueue> = BaseBuilder::new(client, "request-queues".to_owned(), reqwest::Method::POST);
+        builder.append_query_string(format!("name={}", name));
+        let queue = await_test!(builder.send()).unwrap();
+        queue
+    }
+
+    fn delete_request_queue (client: &ApifyClient, id_or_name: &str) -> NoOutput {
+        let no_content = await_test!(client.request_queue(id_or_name).delete().send()).unwrap();
+        no_content
+    }
+
     fn get_run (client: &ApifyClient, id_or_name: &str) -> Result<Run, ApifyClientError> {
         let maybe_run = await_test!(client.run(id_or_name).get().send());
         maybe_run
@@ -139,18 +198,121 @@ mod test {
 
         let dataset = create_dataset(&client, name);
         let dataset_id = dataset.id;
-        
-        /* 
-        let maybe_pagination_list = await_test!(client.list_datasets().limit(10).send());
+
+        let maybe_pagination_list = await_test!(client.datasets().list().desc(true).limit(10).send());
         assert!(maybe_pagination_list.is_ok());
         assert!(maybe_pagination_list.unwrap().items.iter().find(|dataset| dataset.id == dataset_id.clone()).is_some());
 
-        delete_dataset(&client, dataset_id.clone());
+        delete_dataset(&client, &dataset_id);
 
-        let maybe_pagination_list = await_test!(client.list_datasets().limit(10).send());
+        let maybe_pagination_list = await_test!(client.datasets().list().desc(true).limit(10).send());
         assert!(maybe_pagination_list.is_ok());
         assert!(maybe_pagination_list.unwrap().items.iter().find(|dataset| dataset.id == dataset_id).is_none());
-        */
+    }
+
+    #[test]
+    fn stream_dataset_items_test () {
+        let client = create_client();
+        let name = "RUST-TEST-STREAM-ITEMS";
+
+        let dataset = create_dataset(&client, name);
+        let dataset_id = dataset.id;
+
+        let items: Vec<Item> = (0..25).map(|index| Item { field1: index as f64, field2: 0. }).collect();
+        let put_result = push_items(&client, &dataset_id, items.clone());
+        assert!(put_result.is_ok());
+
+        // We have to sleep so that numbers on Apify's side update propagate properly
+        std::thread::sleep(std::time::Duration::from_secs(10));
+
+        let dataset_client = client.dataset(&dataset_id);
+        let maybe_all_items: Result<Vec<Item>, _> = await_test!(dataset_client.list_items().into_stream(10).try_collect());
+        let mut items_builder = dataset_client.list_items();
+        items_builder.offset(5).limit(12);
+        let maybe_some_items: Result<Vec<Item>, _> = await_test!(items_builder.into_stream(5).try_collect());
+
+        delete_dataset(&client, &dataset_id);
+
+        assert_eq!(maybe_all_items.unwrap(), items);
+        assert_eq!(maybe_some_items.unwrap(), items[5..17].to_vec());
+    }
+
+    #[test]
+    fn list_all_items_parallel_test () {
+        let client = create_client();
+        let name = "RUST-TEST-PARALLEL-ITEMS";
+
+        let dataset = create_dataset(&client, name);
+        let dataset_id = dataset.id;
+
+        let items: Vec<Item> = (0..25).map(|index| Item { field1: index as f64, field2: 1. }).collect();
+        let put_result = push_items(&client, &dataset_id, items.clone());
+        assert!(put_result.is_ok());
+
+        // No sleep, items past a stale item_count are still downloaded
+        let dataset_client = client.dataset(&dataset_id);
+        let maybe_items: Result<Vec<Item>, _> = await_test!(dataset_client.list_all_items_parallel(3, 4).send());
+        let maybe_sequential_items: Result<Vec<Item>, _> = await_test!(dataset_client.list_all_items_parallel(0, 10).send());
+        let maybe_streamed_items: Result<Vec<Item>, _> = await_test!(async {
+            dataset_client.list_all_items_parallel(5, 10).into_stream().await?.try_collect().await
+        });
+
+        delete_dataset(&client, &dataset_id);
+
+        assert_eq!(maybe_items.unwrap(), items);
+        assert_eq!(maybe_sequential_items.unwrap(), items);
+        assert_eq!(maybe_streamed_items.unwrap(), items);
+    }
+
+    #[test]
+    fn plan_chunks_test () {
+        let (offsets, remaining_offset) = crate::builders::dataset::plan_chunks(25, 10);
+        assert_eq!(offsets.collect::<Vec<u64>>(), vec![0, 10, 20]);
+        assert_eq!(remaining_offset, Some(30));
+
+        let (offsets, remaining_offset) = crate::builders::dataset::plan_chunks(0, 10);
+        assert_eq!(offsets.count(), 0);
+        assert_eq!(remaining_offset, Some(0));
+
+        // Huge chunk size must not overflow
+        let (offsets, remaining_offset) = crate::builders::dataset::plan_chunks(25, u64::MAX);
+        assert_eq!(offsets.collect::<Vec<u64>>(), vec![0]);
+        assert_eq!(remaining_offset, Some(u64::MAX));
+        let half = u64::MAX / 2 + 1;
+        let (offsets, remaining_offset) = crate::builders::dataset::plan_chunks(u64::MAX, half);
+        assert_eq!(offsets.collect::<Vec<u64>>(), vec![0, half]);
+        assert_eq!(remaining_offset, None);
+    }
+
+    #[test]
+    fn paginate_test () {
+        let all_items: Vec<u64> = (0..23).collect();
+        let requested_pages = std::cell::RefCell::new(Vec::new());
+        let stream = crate::builders::list::paginate(2, Some(13), 5, |offset, limit| {
+            requested_pages.borrow_mut().push((offset, limit));
+            let end = (offset + limit).min(all_items.len() as u64);
+            let items = all_items[offset as usize..end as usize].to_vec();
+            futures::future::ready(Ok(PaginationList { total: 23, offset, limit: Some(limit), count: items.len() as u64, desc: false, items }))
+        });
+        let maybe_items: Result<Vec<u64>, _> = await_test!(stream.try_collect());
+        assert_eq!(maybe_items.unwrap(), all_items[2..15].to_vec());
+        assert_eq!(*requested_pages.borrow(), vec![(2, 5), (7, 5), (12, 3)]);
+
+        let stream = crate::builders::list::paginate(20, None, 5, |offset, limit| {
+            let end = (offset + limit).min(all_items.len() as u64);
+            let items = all_items[offset as usize..end as usize].to_vec();
+            futures::future::ready(Ok(PaginationList { total: 23, offset, limit: Some(limit), count: items.len() as u64, desc: false, items }))
+        });
+        let maybe_items: Result<Vec<u64>, _> = await_test!(stream.try_collect());
+        assert_eq!(maybe_items.unwrap(), vec![20, 21, 22]);
+
+        // Zero page size is treated as 1 instead of looping on the same offset
+        let stream = crate::builders::list::paginate(0, Some(2), 0, |offset, limit| {
+            let items = all_items[offset as usize..(offset + limit) as usize].to_vec();
+            futures::future::ready(Ok(PaginationList { total: 23, offset, limit: Some(limit), count: items.len() as u64, desc: false, items }))
+        });
+        let maybe_items: Result<Vec<u64>, _> = await_test!(stream.try_collect());
+        assert_eq!(maybe_items.unwrap(), vec![0, 1]);
     }
 
     // TODO: Test all formats and most params
@@ -196,6 +358,792 @@ mod test {
         assert_eq!(no_content, NoOutput::new());
     }
 
+    #[test]
+    fn set_get_and_delete_record_test () {
+        let client = create_client();
+        let name = "RUST-TEST-RECORDS";
+
+        let store = create_key_value_store(&client, name);
+        let store_id = store.id;
+
+        let item = get_test_items().remove(0);
+        let json_result = set_record(&client, &store_id, "JSON-RECORD", RecordValue::Json(item.clone()));
+        let raw_result = set_record::<()>(&client, &store_id, "RAW-RECORD", RecordValue::Raw(vec![1, 2, 3]));
+
+        let maybe_json_record = get_record(&client, &store_id, "JSON-RECORD");
+        let maybe_raw_record = get_record(&client, &store_id, "RAW-RECORD");
+
+        let delete_result = await_test!(client.key_value_store(&store_id).delete_record("JSON-RECORD").send());
+        let maybe_deleted_record = get_record(&client, &store_id, "JSON-RECORD");
+
+        let no_content = delete_key_value_store(&client, &store_id);
+
+        // We need to assert here so that we delete the store
+        assert!(json_result.is_ok());
+        assert!(raw_result.is_ok());
+        assert_eq!(maybe_json_record.unwrap().value, RecordValue::Json(item));
+        let raw_record = maybe_raw_record.unwrap();
+        assert_eq!(raw_record.value, RecordValue::Raw(vec![1, 2, 3]));
+        assert_eq!(raw_record.content_type, "application/octet-stream");
+        assert!(delete_result.is_ok());
+        assert!(maybe_deleted_record.is_err());
+        assert_eq!(no_content, NoOutput::new());
+    }
+
+    #[test]
+    fn list_keys_test () {
+        let client = create_client();
+        let name = "RUST-TEST-LIST-KEYS";
+
+        let store = create_key_value_store(&client, name);
+        let store_id = store.id;
+
+        for key in &["KEY-1", "KEY-2", "KEY-3"] {
+            set_record::<()>(&client, &store_id, key, RecordValue::Raw(vec![1])).unwrap();
+        }
+
+        let maybe_first_page = await_test!(client.key_value_store(&store_id).list_keys().limit(2).send());
+        // Page size smaller than number of keys forces walking more pages
+        let maybe_keys = list_all_keys(&client, &store_id, 2);
+
+        let no_content = delete_key_value_store(&client, &store_id);
+
+        // We need to assert here so that we delete the store
+        let first_page = maybe_first_page.unwrap();
+        assert_eq!(first_page.count, 2);
+        assert!(first_page.is_truncated);
+        assert_eq!(first_page.next_exclusive_start_key, Some("KEY-2".to_owned()));
+        let keys: Vec<String> = maybe_keys.unwrap().into_iter().map(|key| key.key).collect();
+        assert_eq!(keys, vec!["KEY-1", "KEY-2", "KEY-3"]);
+        assert_eq!(no_content, NoOutput::new());
+    }
+
+    #[test]
+    fn get_or_create_and_list_key_value_stores_test () {
+        let client = create_client();
+        let name = "RUST-TEST-GET-OR-CREATE";
+
+        let store = create_key_value_store(&client, name);
+        let store_id = store.id;
+        // Second call with the same name must not create a new store
+        let same_store = create_key_value_store(&client, name);
+
+        let maybe_pagination_list = list_key_value_stores(&client);
+
+        let no_content = delete_key_value_store(&client, &store_id);
+
+        let maybe_pagination_list_after_delete = list_key_value_stores(&client);
+
+        // We need to assert here so that we delete the store
+        assert_eq!(same_store.id, store_id);
+        assert_eq!(store.name.unwrap(), name);
+        assert!(maybe_pagination_list.unwrap().items.iter().find(|store| store.id == store_id).is_some());
+        assert!(maybe_pagination_list_after_delete.unwrap().items.iter().find(|store| store.id == store_id).is_none());
+        assert_eq!(no_content, NoOutput::new());
+    }
+
+    #[test]
+    fn add_get_update_and_delete_request_test () {
+        let client = create_client();
+        let name = "RUST-TEST-REQUESTS";
+
+        let queue = create_request_queue(&client, name);
+        let queue_client = client.request_queue(&queue.id);
+
+        let mut request = Request::new("https://example.com");
+        request.user_data = Some(json!({ "label": "START" }));
+        let maybe_info = await_test!(queue_client.add_request(request.clone()).send());
+        let maybe_forefront_info = await_test!(queue_client.add_request(Request::new("https://example.com/first")).forefront(true).send());
+        let maybe_duplicate_info = await_test!(queue_client.add_request(request.clone()).send());
+
+        let request_id = maybe_info.as_ref().unwrap().request_id.clone();
+        let maybe_request = await_test!(queue_client.get_request(&request_id).send());
+
+        let maybe_head = await_test!(queue_client.list_head().limit(10).send());
+
+        let mut handled_request = maybe_request.as_ref().unwrap().clone();
+        handled_request.handled_at = Some("2021-01-01T00:00:00.000Z".to_owned());
+        let maybe_update_info = await_test!(queue_client.update_request(handled_request).send());
+
+        let delete_result = await_test!(queue_client.delete_request(&request_id).send());
+        let maybe_deleted_request = await_test!(queue_client.get_request(&request_id).send());
+
+        let no_content = delete_request_queue(&client, &queue.id);
+
+        // We need to assert here so that we delete the queue
+        assert!(!maybe_info.unwrap().was_already_present);
+        assert!(maybe_duplicate_info.unwrap().was_already_present);
+        let request_from_queue = maybe_request.unwrap();
+        assert_eq!(request_from_queue.url, request.url);
+        assert_eq!(request_from_queue.user_data, request.user_data);
+        let head = maybe_head.unwrap();
+        assert_eq!(head.items.len(), 2);
+        assert_eq!(head.items[0].id, Some(maybe_forefront_info.unwrap().request_id));
+        assert!(!maybe_update_info.unwrap().was_already_handled);
+        assert!(delete_result.is_ok());
+        assert!(maybe_deleted_request.is_err());
+        assert_eq!(no_content, NoOutput::new());
+    }
+
+    #[test]
+    fn batch_add_and_delete_requests_test () {
+        let client = create_client();
+        let name = "RUST-TEST-BATCH-REQUESTS";
+
+        let queue = create_request_queue(&client, name);
+        let queue_client = client.request_queue(&queue.id);
+
+        // Not divisible by the batch size so the last batch is partial
+        let requests: Vec<Request> = (0..60).map(|i| Request::new(&format!("https://example.com/{}", i))).collect();
+        let maybe_add_result = await_test!(queue_client.batch_add_requests(requests.clone()).parallelism(2).send());
+
+        // We have to sleep so that numbers on Apify's side update propagate properly
+        std::thread::sleep(std::time::Duration::from_secs(10));
+        let maybe_queue = await_test!(queue_client.get().send());
+
+        let identifiers = requests.iter().map(|request| RequestIdentifier::unique_key(&request.unique_key)).collect();
+        let maybe_delete_result = await_test!(queue_client.batch_delete_requests(identifiers).send());
+
+        let no_content = delete_request_queue(&client, &queue.id);
+
+        // We need to assert here so that we delete the queue
+        let add_result = maybe_add_result.unwrap();
+        assert!(add_result.errors.is_empty());
+        assert_eq!(add_result.processed_requests.len(), 60);
+        assert!(add_result.unprocessed_requests.is_empty());
+        assert_eq!(maybe_queue.unwrap().total_request_count, 60);
+        let delete_result = maybe_delete_result.unwrap();
+        assert!(delete_result.errors.is_empty());
+        assert_eq!(delete_result.processed_requests.len(), 60);
+        assert!(delete_result.unprocessed_requests.is_empty());
+        assert_eq!(no_content, NoOutput::new());
+    }
+
+    #[test]
+    fn set_record_invalid_content_type_test () {
+        let client = create_client();
+        let store_client = client.key_value_store("RUST-TEST-NONEXISTENT-STORE");
+
+        let mut set_record_builder = store_client.set_record::<serde_json::Value>("KEY", RecordValue::Raw(b"value".to_vec()));
+        set_record_builder.content_type("text/plain\n");
+        let maybe_set = await_test!(set_record_builder.send());
+        assert!(matches!(
+            maybe_set,
+            Err(ApifyClientError::ClientValidation(ClientValidationError::InvalidContentType(_)))
+        ));
+    }
+
+    #[test]
+    fn start_run_invalid_content_type_test () {
+        let client = create_client();
+
+        let mut start_builder = client.actor("apify~hello-world").start();
+        start_builder.raw_input(b"input".to_vec(), "text/plain\n");
+        let maybe_run = await_test!(start_builder.send());
+        assert!(matches!(
+            maybe_run,
+            Err(ApifyClientError::ClientValidation(ClientValidationError::InvalidContentType(_)))
+        ));
+    }
+
+    #[test]
+    fn update_request_without_id_test () {
+        let client = create_client();
+        let queue_client = client.request_queue("RUST-TEST-NONEXISTENT-QUEUE");
+
+        // Fails before sending anything
+        let maybe_updated = await_test!(queue_client.update_request(Request::new("https://example.com")).send());
+        assert!(matches!(
+            maybe_updated,
+            Err(ApifyClientError::ClientValidation(ClientValidationError::MissingRequestId))
+        ));
+    }
+
+    #[test]
+    fn batch_add_requests_errors_test () {
+        let client = create_client();
+        let queue_client = client.request_queue("RUST-TEST-NONEXISTENT-QUEUE");
+
+        let requests: Vec<Request> = (0..30).map(|i| Request::new(&format!("https://example.com/{}", i))).collect();
+        // Zero parallelism is treated as 1 instead of never sending anything
+        let maybe_add_result = await_test!(queue_client.batch_add_requests(requests).parallelism(0).max_unprocessed_retries(0).send());
+
+        // Both batch calls fail so the whole operation fails
+        assert!(maybe_add_result.is_err());
+
+        let maybe_add_result = await_test!(queue_client.batch_add_requests(Vec::new()).send());
+        assert!(maybe_add_result.unwrap().processed_requests.is_empty());
+    }
+
+    #[test]
+    fn lock_prolong_and_delete_lock_test () {
+        let client = create_client();
+        let name = "RUST-TEST-LOCKS";
+
+        let queue = create_request_queue(&client, name);
+        let queue_client = client.request_queue(&queue.id);
+
+        let requests: Vec<Request> = (0..3).map(|i| Request::new(&format!("https://example.com/{}", i))).collect();
+        assert!(await_test!(queue_client.batch_add_requests(requests).send()).unwrap().errors.is_empty());
+
+        let maybe_locked_head = await_test!(queue_client.list_and_lock_head(60, 2).client_key("consumer-1").send());
+        // Locked requests must not be given to another consumer
+        let maybe_other_head = await_test!(queue_client.list_and_lock_head(60, 10).client_key("consumer-2").send());
+
+        let locked_head = maybe_locked_head.unwrap();
+        let locked_request = locked_head.items[0].clone();
+        let maybe_lock_info = await_test!(queue_client.prolong_request_lock(&locked_request.id, 120).client_key("consumer-1").send());
+        let delete_lock_result = await_test!(queue_client.delete_request_lock(&locked_request.id).client_key("consumer-1").send());
+
+        let no_content = delete_request_queue(&client, &queue.id);
+
+        // We need to assert here so that we delete the queue
+        assert_eq!(locked_head.items.len(), 2);
+        assert_eq!(maybe_other_head.unwrap().items.len(), 1);
+        assert!(maybe_lock_info.unwrap().lock_expires_at > locked_request.lock_expires_at);
+        assert!(delete_lock_result.is_ok());
+        assert_eq!(no_content, NoOutput::new());
+    }
+
+    #[test]
+    fn get_start_and_call_actor_test () {
+        let client = create_client();
+        let actor_client = client.actor("apify~hello-world");
+
+        let maybe_actor = await_test!(actor_client.get().send());
+        assert_eq!(maybe_actor.unwrap().name, "hello-world");
+
+        let maybe_started_run = await_test!(actor_client.start().input(json!({ "message": "start" })).memory(256).send());
+        let started_run = maybe_started_run.unwrap();
+        assert!(started_run.finished_at.is_none() || started_run.status == RunStatus::Succeeded);
+
+        let maybe_finished_run = await_test!(actor_client.call().input(json!({ "message": "call" })).memory(256).send());
+        let finished_run = maybe_finished_run.unwrap();
+        assert_eq!(finished_run.status, RunStatus::Succeeded);
+        assert_eq!(finished_run.options.memory_mbytes, 256);
+    }
+
+    #[test]
+    fn wait_for_finish_test () {
+        let client = create_client();
+
+        let maybe_started_run = await_test!(client.actor("apify~hello-world").start().memory(256).send());
+        let started_run = maybe_started_run.unwrap();
+
+        // Zero deadline returns right after the first fetch
+        let maybe_unfinished_run = await_test!(client.run(&started_run.id).wait_for_finish(Some(std::time::Duration::from_secs(0))).send());
+        let maybe_finished_run = await_test!(client.run(&started_run.id).wait_for_finish(Some(std::time::Duration::from_secs(300))).send());
+
+        assert_eq!(maybe_unfinished_run.unwrap().id, started_run.id);
+        let finished_run = maybe_finished_run.unwrap();
+        assert!(finished_run.is_terminal());
+        assert_eq!(finished_run.status, RunStatus::Succeeded);
+    }
+
+    #[test]
+    fn abort_and_resurrect_run_test () {
+        let client = create_client();
+
+        let maybe_started_run = await_test!(client.actor("apify~hello-world").start().memory(256).send());
+        let run_client = client.run(&maybe_started_run.unwrap().id);
+
+        let maybe_aborted_run = await_test!(run_client.abort().send());
+        let aborted_run = await_test!(run_client.wait_for_finish(None).send()).unwrap();
+
+        let maybe_resurrected_run = await_test!(run_client.resurrect().memory(512).send());
+        let resurrected_run = await_test!(run_client.wait_for_finish(None).send()).unwrap();
+
+        assert!(maybe_aborted_run.is_ok());
+        assert_eq!(aborted_run.status, RunStatus::Aborted);
+        assert!(maybe_resurrected_run.is_ok());
+        assert_eq!(resurrected_run.stats.resurrect_count, 1);
+        assert_eq!(resurrected_run.options.memory_mbytes, 512);
+    }
+
+    #[test]
+    fn get_and_stream_log_test () {
+        let client = create_client();
+
+        let maybe_started_run = await_test!(client.actor("apify~hello-world").start().memory(256).send());
+        let run_id = maybe_started_run.unwrap().id;
+
+        // Stream ends once the run finishes
+        let maybe_streamed_lines: Result<Vec<String>, ApifyClientError> = await_test!(async {
+            let log_stream = client.run(&run_id).log().stream().send().await?;
+            log_stream.try_collect().await
+        });
+        let maybe_log = await_test!(client.log(&run_id).get().send());
+
+        let streamed_lines = maybe_streamed_lines.unwrap();
+        let log = maybe_log.unwrap();
+        assert!(!streamed_lines.is_empty());
+        assert_eq!(streamed_lines, log.lines().collect::<Vec<&str>>());
+    }
+
+    #[test]
+    fn run_default_storages_test () {
+        let client = create_client();
+
+        let maybe_run = await_test!(client.actor("apify~hello-world").call().memory(256).send());
+        let run = maybe_run.unwrap();
+        let run_client = client.run(&run.id);
+
+        let maybe_dataset = await_test!(run_client.dataset().get().send());
+        let maybe_store = await_test!(run_client.key_value_store().get().send());
+        let maybe_queue = await_test!(run_client.request_queue().get().send());
+        let maybe_pagination_list = await_test!(run_client.dataset().list_items::<serde_json::Value>().send());
+
+        assert_eq!(maybe_dataset.unwrap().id, run.default_dataset_id);
+        assert_eq!(maybe_store.unwrap().id, run.default_key_value_store_id);
+        assert_eq!(maybe_queue.unwrap().id, run.default_request_queue_id);
+        assert!(maybe_pagination_list.is_ok());
+    }
+
+    #[test]
+    fn last_run_test () {
+        let client = create_client();
+        let actor_client = client.actor("apify~hello-world");
+
+        let maybe_run = await_test!(actor_client.call().memory(256).send());
+        let run = maybe_run.unwrap();
+
+        let last_succeeded_run = actor_client.last_run(Some(RunStatus::Succeeded));
+        let maybe_last_run = await_test!(last_succeeded_run.get().send());
+        let maybe_last_dataset = await_test!(last_succeeded_run.dataset().get().send());
+        let maybe_last_log = await_test!(last_succeeded_run.log().get().send());
+
+        assert_eq!(maybe_last_run.unwrap().id, run.id);
+        assert_eq!(maybe_last_dataset.unwrap().id, run.default_dataset_id);
+        assert!(maybe_last_log.is_ok());
+    }
+
+    #[test]
+    fn list_runs_test () {
+        let client = create_client();
+        let actor_client = client.actor("apify~hello-world");
+
+        let maybe_run = await_test!(actor_client.call().memory(256).send());
+        let run = maybe_run.unwrap();
+
+        let maybe_actor_runs = await_test!(actor_client.runs().list().status(RunStatus::Succeeded).desc(true).limit(10).send());
+        let actor_runs = maybe_actor_runs.unwrap();
+        assert!(actor_runs.items.iter().all(|run_short| run_short.status == RunStatus::Succeeded));
+        assert!(actor_runs.items.iter().all(|run_short| run_short.actor_id == run.actor_id));
+        assert!(actor_runs.items.iter().any(|run_short| run_short.id == run.id));
+
+        let maybe_user_runs = await_test!(client.runs().list().desc(true).limit(10).send());
+        assert!(maybe_user_runs.unwrap().items.iter().any(|run_short| run_short.id == run.id));
+    }
+
+    #[test]
+    fn create_update_call_and_delete_task_test () {
+        let client = create_client();
+        let name = "RUST-TEST-TASK";
+
+        let definition = TaskDefinition {
+            actor_id: Some("apify~hello-world".to_owned()),
+            name: Some(name.to_owned()),
+            options: Some(TaskOptions { memory_mbytes: Some(256), ..Default::default() }),
+            input: Some(serde_json::json!({ "message": "Hello from task" })),
+            ..Default::default()
+        };
+        let maybe_task = await_test!(client.tasks().create(definition).send());
+        let task = maybe_task.unwrap();
+        assert_eq!(task.name, name);
+        let task_client = client.task(&task.id);
+
+        let maybe_tasks = await_test!(client.tasks().list().desc(true).limit(10).send());
+        assert!(maybe_tasks.unwrap().items.iter().any(|task_short| task_short.id == task.id));
+
+        let update = TaskDefinition { title: Some("Rust test task".to_owned()), ..Default::default() };
+        let maybe_updated_task = await_test!(task_client.update(update).send());
+        assert_eq!(maybe_updated_task.unwrap().title, Some("Rust test task".to_owned()));
+
+        let maybe_input = await_test!(task_client.update_input(serde_json::json!({ "message": "Updated" })).send());
+        assert_eq!(maybe_input.unwrap(), serde_json::json!({ "message": "Updated" }));
+        let maybe_input = await_test!(task_client.get_input().send());
+        assert_eq!(maybe_input.unwrap(), serde_json::json!({ "message": "Updated" }));
+
+        let maybe_run = await_test!(task_client.call().send());
+        let run = maybe_run.unwrap();
+        assert_eq!(run.status, RunStatus::Succeeded);
+        assert_eq!(run.actor_task_id, Some(task.id.clone()));
+
+        let maybe_deleted = await_test!(task_client.delete().send());
+        assert!(maybe_deleted.is_ok());
+        let maybe_task = await_test!(task_client.get().send());
+        assert!(maybe_task.is_err());
+    }
+
+    #[test]
+    fn build_abort_and_list_builds_test () {
+        let client = create_client();
+
+        let definition = ActorDefinition {
+            name: Some("rust-test-build-actor".to_owned()),
+            versions: Some(vec![test_actor_version()]),
+            ..Default::default()
+        };
+        let maybe_actor = await_test!(client.actors().create(definition).send());
+        let actor = maybe_actor.unwrap();
+        let actor_client = client.actor(&actor.id);
+
+        let maybe_aborted_build = await_test!(actor_client.build("0.0").use_cache(false).send());
+        let aborted_build_client = client.build(&maybe_aborted_build.unwrap().id);
+        let maybe_aborting_build = await_test!(aborted_build_client.abort().send());
+        assert!(maybe_aborting_build.is_ok());
+        let maybe_aborted_build = await_test!(aborted_build_client.wait_for_finish(None).send());
+        assert_eq!(maybe_aborted_build.unwrap().status, RunStatus::Aborted);
+
+        let maybe_build = await_test!(actor_client.build("0.0").tag("latest").send());
+        let build = maybe_build.unwrap();
+        let build_client = client.build(&build.id);
+        let maybe_finished_build = await_test!(build_client.wait_for_finish(None).send());
+        let finished_build = maybe_finished_build.unwrap();
+        assert!(finished_build.is_terminal());
+        assert_eq!(finished_build.status, RunStatus::Succeeded);
+
+        let maybe_build = await_test!(build_client.get().send());
+        assert_eq!(maybe_build.unwrap().actor_id, actor.id);
+        let maybe_log = await_test!(build_client.log().get().send());
+        assert!(!maybe_log.unwrap().is_empty());
+
+        let maybe_actor_builds = await_test!(actor_client.builds().list().limit(10).send());
+        let actor_builds = maybe_actor_builds.unwrap();
+        assert_eq!(actor_builds.items.len(), 2);
+        assert!(actor_builds.items.iter().all(|build_short| build_short.actor_id == actor.id));
+        let maybe_builds = await_test!(client.builds().list().desc(true).limit(10).send());
+        assert!(maybe_builds.unwrap().items.iter().any(|build_short| build_short.id == build.id));
+
+        let maybe_deleted = await_test!(actor_client.delete().send());
+        assert!(maybe_deleted.is_ok());
+    }
+
+    fn source_file (name: &str, content: &str) -> ActorSourceFile {
+        ActorSourceFile {
+            name: name.to_owned(),
+            format: Some(ActorSourceFileFormat::Text),
+            content: Some(content.to_owned()),
+            folder: None,
+        }
+    }
+
+    fn test_actor_version () -> ActorVersion {
+        ActorVersion {
+            version_number: "0.0".to_owned(),
+            build_tag: Some("latest".to_owned()),
+            env_vars: None,
+            apply_env_vars_to_build: None,
+            source: ActorVersionSource::SourceFiles {
+                source_files: vec![
+                    source_file("Dockerfile", "FROM node:16-alpine\nCOPY . ./\nCMD node main.js"),
+                    source_file("main.js", "console.log('Hello from Rust test');"),
+                ],
+            },
+        }
+    }
+
+    #[test]
+    fn update_version_and_env_vars_test () {
+        let client = create_client();
+
+        let definition = ActorDefinition {
+            name: Some("rust-test-env-vars-actor".to_owned()),
+            versions: Some(vec![test_actor_version()]),
+            ..Default::default()
+        };
+        let maybe_actor = await_test!(client.actors().create(definition).send());
+        let actor_client = client.actor(&maybe_actor.unwrap().id);
+        let version_client = actor_client.version("0.0");
+
+        // Source is left as it is
+        let update = ActorVersionUpdate { build_tag: Some("beta".to_owned()), ..Default::default() };
+        let maybe_updated_version = await_test!(version_client.update(update).send());
+        let updated_version = maybe_updated_version.unwrap();
+        assert_eq!(updated_version.build_tag, Some("beta".to_owned()));
+        assert_eq!(updated_version.source, test_actor_version().source);
+
+        let maybe_env_var = await_test!(version_client.env_vars().create(ActorEnvVar::new("TEST_VAR", "first", false)).send());
+        assert_eq!(maybe_env_var.unwrap().value, Some("first".to_owned()));
+        let env_var_client = version_client.env_var("TEST_VAR");
+        let maybe_env_var = await_test!(env_var_client.update(ActorEnvVar::new("TEST_VAR", "second", false)).send());
+        assert!(maybe_env_var.is_ok());
+        let maybe_env_var = await_test!(env_var_client.get().send());
+        assert_eq!(maybe_env_var.unwrap().value, Some("second".to_owned()));
+        let maybe_env_vars = await_test!(version_client.env_vars().list().send());
+        assert!(maybe_env_vars.unwrap().items.iter().any(|env_var| env_var.name == "TEST_VAR"));
+
+        let maybe_deleted = await_test!(env_var_client.delete().send());
+        assert!(maybe_deleted.is_ok());
+        let maybe_env_var = await_test!(env_var_client.get().send());
+        assert!(maybe_env_var.is_err());
+
+        let maybe_deleted = await_test!(actor_client.delete().send());
+        assert!(maybe_deleted.is_ok());
+    }
+
+    #[test]
+    fn create_list_version_build_and_delete_actor_test () {
+        let client = create_client();
+        let name = "rust-test-actor";
+
+        let version = test_actor_version();
+        let definition = ActorDefinition {
+            name: Some(name.to_owned()),
+            versions: Some(vec![version.clone()]),
+            default_run_options: Some(ActorDefaultRunOptions { memory_mbytes: Some(256), ..Default::default() }),
+            ..Default::default()
+        };
+        let maybe_actor = await_test!(client.actors().create(definition).send());
+        let actor = maybe_actor.unwrap();
+        assert_eq!(actor.name, name);
+        assert_eq!(actor.default_run_options.memory_mbytes, Some(256));
+        let actor_client = client.actor(&actor.id);
+
+        let maybe_actors = await_test!(client.actors().list().my(true).desc(true).limit(10).send());
+        assert!(maybe_actors.unwrap().items.iter().any(|actor_short| actor_short.id == actor.id));
+
+        let maybe_versions = await_test!(actor_client.versions().list().send());
+        assert_eq!(maybe_versions.unwrap().items[0].version_number, "0.0");
+
+        let maybe_build = await_test!(actor_client.build("0.0").tag("latest").use_cache(false).send());
+        let build = maybe_build.unwrap();
+        let maybe_finished_build = await_test!(client.build(&build.id).wait_for_finish(None).send());
+        assert_eq!(maybe_finished_build.unwrap().status, RunStatus::Succeeded);
+
+        let maybe_deleted = await_test!(actor_client.delete().send());
+        assert!(maybe_deleted.is_ok());
+    }
+
+    #[test]
+    fn create_update_and_delete_schedule_test () {
+        let client = create_client();
+        let name = "rust-test-schedule";
+
+        let maybe_actor = await_test!(client.actor("apify~hello-world").get().send());
+        let actor_id = maybe_actor.unwrap().id;
+
+        let definition = ScheduleDefinition {
+            name: Some(name.to_owned()),
+            cron_expression: Some("0 8 * * *".to_owned()),
+            timezone: Some("Europe/Prague".to_owned()),
+            is_enabled: Some(false),
+            actions: Some(vec![ScheduleAction::RunActor {
+                id: None,
+                actor_id: actor_id.clone(),
+                run_input: Some(ScheduleRunInput::json(&json!({ "message": "schedule" }))),
+                run_options: Some(ScheduleRunOptions { memory_mbytes: Some(256), ..Default::default() }),
+            }]),
+            ..Default::default()
+        };
+        let maybe_schedule = await_test!(client.schedules().create(definition).send());
+        let schedule = maybe_schedule.unwrap();
+        assert_eq!(schedule.timezone, "Europe/Prague");
+        match &schedule.actions[0] {
+            ScheduleAction::RunActor { actor_id: action_actor_id, .. } => assert_eq!(action_actor_id, &actor_id),
+            action => panic!("Unexpected schedule action {:?}", action),
+        }
+        let schedule_client = client.schedule(&schedule.id);
+
+        let maybe_schedules = await_test!(client.schedules().list().desc(true).limit(10).send());
+        assert!(maybe_schedules.unwrap().items.iter().any(|listed_schedule| listed_schedule.id == schedule.id));
+
+        let update = ScheduleDefinition { cron_expression: Some("@daily".to_owned()), ..Default::default() };
+        let maybe_updated_schedule = await_test!(schedule_client.update(update).send());
+        assert_eq!(maybe_updated_schedule.unwrap().cron_expression, "@daily");
+
+        let maybe_log = await_test!(schedule_client.get_log().send());
+        assert!(maybe_log.is_ok());
+
+        let maybe_deleted = await_test!(schedule_client.delete().send());
+        assert!(maybe_deleted.is_ok());
+    }
+
+    #[test]
+    fn create_test_and_delete_webhook_test () {
+        let client = create_client();
+
+        let maybe_actor = await_test!(client.actor("apify~hello-world").get().send());
+        let actor_id = maybe_actor.unwrap().id;
+
+        let definition = WebhookDefinition {
+            event_types: Some(vec![WebhookEventType::ActorRunSucceeded, WebhookEventType::ActorRunFailed]),
+            condition: Some(WebhookCondition { actor_id: Some(actor_id.clone()), ..Default::default() }),
+            request_url: Some("https://example.com/rust-test-webhook".to_owned()),
+            payload_template: Some(r#"{"resource": {{resource}}}"#.to_owned()),
+            ..Default::default()
+        };
+        let maybe_webhook = await_test!(client.webhooks().create(definition).send());
+        let webhook = maybe_webhook.unwrap();
+        assert_eq!(webhook.event_types, vec![WebhookEventType::ActorRunSucceeded, WebhookEventType::ActorRunFailed]);
+        assert_eq!(webhook.condition.actor_id, Some(actor_id));
+        let webhook_client = client.webhook(&webhook.id);
+
+        let maybe_webhooks = await_test!(client.webhooks().list().desc(true).limit(10).send());
+        assert!(maybe_webhooks.unwrap().items.iter().any(|listed_webhook| listed_webhook.id == webhook.id));
+
+        let update = WebhookDefinition { do_not_retry: Some(true), ..Default::default() };
+        let maybe_updated_webhook = await_test!(webhook_client.update(update).send());
+        assert!(maybe_updated_webhook.unwrap().do_not_retry);
+
+        let maybe_dispatch = await_test!(webhook_client.test().send());
+        let dispatch = maybe_dispatch.unwrap();
+        assert_eq!(dispatch.webhook_id, webhook.id);
+
+        let maybe_fetched_dispatch = await_test!(client.webhook_dispatch(&dispatch.id).get().send());
+        assert_eq!(maybe_fetched_dispatch.unwrap().id, dispatch.id);
+        let maybe_dispatches = await_test!(webhook_client.dispatches().list().send());
+        assert!(maybe_dispatches.unwrap().items.iter().any(|listed_dispatch| listed_dispatch.id == dispatch.id));
+
+        let maybe_deleted = await_test!(webhook_client.delete().send());
+        assert!(maybe_deleted.is_ok());
+    }
+
+    #[test]
+    fn get_user_usage_and_limits_test () {
+        let client = create_client();
+        let user_client = client.user("me");
+
+        let maybe_user = await_test!(user_client.get().send());
+        let user = maybe_user.unwrap();
+        assert!(user.plan.is_some());
+
+        let maybe_usage = await_test!(user_client.monthly_usage().send());
+        let usage = maybe_usage.unwrap();
+        assert!(usage.total_usage_credits_usd_after_volume_discount >= 0.0);
+
+        let maybe_limits = await_test!(user_client.limits().send());
+        let limits = maybe_limits.unwrap();
+
+        // Sets the same value back so the account is not changed
+        let update = LimitsUpdate { data_retention_days: Some(limits.limits.data_retention_days), ..Default::default() };
+        let maybe_updated = await_test!(user_client.update_limits(update).send());
+        assert!(maybe_updated.is_ok());
+    }
+
+    #[test]
+    fn service_usage_test () {
+        let service_usage: ServiceUsage = serde_json::from_value(json!({
+            "DATASET_READS": { "quantity": 1200, "baseAmountUsd": 0.0048 },
+            "DATASET_WRITES": { "quantity": 3.0, "baseAmountUsd": 0.0001 },
+            "ACTOR_COMPUTE_UNITS": { "quantity": 0.5, "baseAmountUsd": 0.125 },
+            "SERVICE_ADDED_LATER": { "quantity": 1, "baseAmountUsd": 0.1 },
+        })).unwrap();
+        assert_eq!(service_usage.usage.dataset_reads, 1200);
+        assert_eq!(service_usage.usage.dataset_writes, 3);
+        assert_eq!(service_usage.usage.actor_compute_units, 0.5);
+        assert_eq!(service_usage.usage_usd.dataset_reads, 0.0048);
+        assert_eq!(service_usage.usage.key_value_store_reads, 0);
+    }
+
+    #[test]
+    fn webhook_event_type_test () {
+        let event_types: Vec<WebhookEventType> = serde_json::from_value(json!(["ACTOR.BUILD.TIMED_OUT", "ACTOR.RUN.NEW_EVENT"])).unwrap();
+        assert_eq!(event_types, vec![WebhookEventType::ActorBuildTimedOut, WebhookEventType::Unknown("ACTOR.RUN.NEW_EVENT".to_owned())]);
+        assert_eq!(serde_json::to_value(&event_types).unwrap(), json!(["ACTOR.BUILD.TIMED_OUT", "ACTOR.RUN.NEW_EVENT"]));
+    }
+
+    #[test]
+    fn actor_version_source_test () {
+        let git_repo_version: ActorVersion = serde_json::from_value(json!({
+            "versionNumber": "0.1",
+            "buildTag": "latest",
+            "sourceType": "GIT_REPO",
+            "gitRepoUrl": "https://github.com/apify/hello-world#master",
+        })).unwrap();
+        assert_eq!(git_repo_version.source, ActorVersionSource::GitRepo {
+            git_repo_url: "https://github.com/apify/hello-world#master".to_owned(),
+        });
+
+        let gist_version = ActorVersion {
+            version_number: "0.2".to_owned(),
+            build_tag: None,
+            env_vars: None,
+            apply_env_vars_to_build: None,
+            source: ActorVersionSource::GithubGist { github_gist_url: "https://gist.github.com/abc".to_owned() },
+        };
+        assert_eq!(serde_json::to_value(&gist_version).unwrap(), json!({
+            "versionNumber": "0.2",
+            "sourceType": "GITHUB_GIST",
+            "gitHubGistUrl": "https://gist.github.com/abc",
+        }));
+
+        let new_source_version: ActorVersion = serde_json::from_value(json!({
+            "versionNumber": "0.3",
+            "sourceType": "SOURCE_ADDED_LATER",
+            "sourceUrl": "https://example.com",
+        })).unwrap();
+        assert_eq!(new_source_version.source, ActorVersionSource::Unknown);
+
+        let update = ActorVersionUpdate { build_tag: Some("beta".to_owned()), ..Default::default() };
+        assert_eq!(serde_json::to_value(&update).unwrap(), json!({ "buildTag": "beta" }));
+    }
+
+    #[test]
+    fn list_store_test () {
+        let client = create_client();
+
+        let maybe_store_actors = await_test!(client.store().list().search("web scraper").sort_by(StoreSortBy::Popularity).limit(5).send());
+        let store_actors = maybe_store_actors.unwrap();
+        assert!(store_actors.items.len() <= 5);
+        assert!(!store_actors.items.is_empty());
+
+        let maybe_free_actors = await_test!(client.store().list().username("apify").pricing_model(PricingModel::Free).limit(10).send());
+        let free_actors = maybe_free_actors.unwrap();
+        assert!(free_actors.items.iter().all(|store_actor| store_actor.username == "apify"));
+        assert!(free_actors.items.iter().all(|store_actor| match &store_actor.current_pricing_info {
+            Some(pricing_info) => pricing_info.pricing_model == PricingModel::Free,
+            None => true,
+        }));
+    }
+
+    #[test]
+    fn encode_query_value_test () {
+        assert_eq!(crate::utils::encode_query_value("web scraper&x=1"), "web%20scraper%26x%3D1");
+        assert_eq!(crate::utils::encode_query_value("FREE"), "FREE");
+    }
+
+    #[test]
+    fn encode_path_segment_test () {
+        assert_eq!(crate::utils::encode_path_segment("images/cat 1.png"), "images%2Fcat%201.png");
+        assert_eq!(crate::utils::encode_path_segment("OUTPUT"), "OUTPUT");
+    }
+
+    #[test]
+    fn last_run_status_filter_test () {
+        let client = ApifyClient::new(None);
+        let last_run = client.actor("abc").last_run(Some(RunStatus::Succeeded));
+        assert_eq!(last_run.url_segment, "acts/abc/runs/last");
+        let dataset_client = last_run.dataset();
+        assert_eq!(dataset_client.url_segment, "acts/abc/runs/last/dataset");
+        assert_eq!(crate::utils::run_status_query_string(&dataset_client.run_status), "status=SUCCEEDED");
+        assert_eq!(crate::utils::run_status_query_string(&client.actor("abc").last_run(None).status), "");
+    }
+
+    #[test]
+    fn time_to_next_retry_test () {
+        let client = ApifyClient::new(None);
+        assert_eq!(client.get_time_to_next_retry(1), 1000);
+        assert_eq!(client.get_time_to_next_retry(3), 4000);
+        // Would overflow u32 without the cap
+        assert_eq!(client.get_time_to_next_retry(40), 10 * 60 * 1000);
+        assert_eq!(client.get_time_to_next_retry(u8::MAX), 10 * 60 * 1000);
+    }
+
+    #[test]
+    fn run_status_test () {
+        let statuses: Vec<RunStatus> = serde_json::from_str(r#"["READY", "TIMING-OUT", "TIMED-OUT", "SOME-NEW-STATUS"]"#).unwrap();
+        assert_eq!(statuses, vec![
+            RunStatus::Ready,
+            RunStatus::TimingOut,
+            RunStatus::TimedOut,
+            RunStatus::Unknown("SOME-NEW-STATUS".to_owned()),
+        ]);
+        assert!(!statuses[1].is_terminal());
+        assert!(statuses[2].is_terminal());
+        assert_eq!(serde_json::to_string(&statuses).unwrap(), r#"["READY","TIMING-OUT","TIMED-OUT","SOME-NEW-STATUS"]"#);
+    }
+
     #[test]
     fn get_run_test () {
         let client = create_client();
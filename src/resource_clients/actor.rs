@@ -0,0 +1,186 @@
+use crate::apify_client::ApifyClient;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use crate::base_clients::resource_client::ResourceClient;
+use crate::generic_types::BaseBuilder;
+use crate::error::ApifyClientError;
+use crate::builders::run::StartRunBuilder;
+use crate::builders::build::BuildActorBuilder;
+use crate::resource_clients::build_collection::BuildCollectionClient;
+use crate::resource_clients::actor_version::{ActorVersion, ActorVersionClient};
+use crate::resource_clients::actor_version_collection::ActorVersionCollectionClient;
+use crate::resource_clients::run::{RunClient, RunStatus};
+use crate::resource_clients::run_collection::RunCollectionClient;
+
+pub struct ActorClient<'a> {
+    pub apify_client: &'a ApifyClient,
+    pub url_segment: String,
+}
+
+// See comment on the ResourceClient trait why this boilerplate is needed
+impl <'a> ResourceClient<'a, Actor> for ActorClient<'a> {
+    fn get_client(&self) -> &'a ApifyClient {
+        self.apify_client
+    }
+
+    fn get_url_segment(&self) -> &str {
+        &self.url_segment
+    }
+}
+
+impl <'a> ActorClient<'a> {
+    pub fn new(apify_client: &'a ApifyClient, identifier: &str) -> Self {
+        ActorClient {
+            apify_client,
+            url_segment: format!("acts/{}", identifier),
+        }
+    }
+
+    /// Only fields that are set on the definition are updated
+    pub fn update(&self, definition: ActorDefinition) -> UpdateActorBuilder {
+        UpdateActorBuilder {
+            actor_client: self,
+            payload: definition,
+        }
+    }
+
+    /// Starts a run and returns it right away without waiting for it to finish
+    pub fn start(&self) -> StartRunBuilder<'a> {
+        StartRunBuilder::new(self.apify_client, format!("{}/runs", self.url_segment), false)
+    }
+
+    /// Starts a run and waits until it finishes
+    pub fn call(&self) -> StartRunBuilder<'a> {
+        StartRunBuilder::new(self.apify_client, format!("{}/runs", self.url_segment), true)
+    }
+
+    /// Last run of the actor, optionally only from runs with the given status
+    pub fn last_run(&self, status: Option<RunStatus>) -> RunClient<'a> {
+        RunClient::new_last_run(self.apify_client, &self.url_segment, status)
+    }
+
+    pub fn runs(&self) -> RunCollectionClient<'a> {
+        RunCollectionClient {
+            apify_client: self.apify_client,
+            url_segment: format!("{}/runs", self.url_segment),
+        }
+    }
+
+    /// Builds the given version of the actor, e.g. `0.0`
+    pub fn build(&self, version_number: &str) -> BuildActorBuilder<'a> {
+        BuildActorBuilder::new(self.apify_client, format!("{}/builds", self.url_segment), version_number)
+    }
+
+    pub fn builds(&self) -> BuildCollectionClient<'a> {
+        BuildCollectionClient {
+            apify_client: self.apify_client,
+            url_segment: format!("{}/builds", self.url_segment),
+        }
+    }
+
+    pub fn versions(&self) -> ActorVersionCollectionClient<'a> {
+        ActorVersionCollectionClient {
+            apify_client: self.apify_client,
+            url_segment: format!("{}/versions", self.url_segment),
+        }
+    }
+
+    /// Version by its number, e.g. `0.0`
+    pub fn version(&self, version_number: &str) -> ActorVersionClient<'a> {
+        ActorVersionClient {
+            apify_client: self.apify_client,
+            url_segment: format!("{}/versions/{}", self.url_segment, version_number),
+        }
+    }
+}
+
+pub struct UpdateActorBuilder<'a> {
+    actor_client: &'a ActorClient<'a>,
+    payload: ActorDefinition,
+}
+
+impl <'a> UpdateActorBuilder<'a> {
+    pub async fn send(self) -> Result<Actor, ApifyClientError> {
+        let mut builder: BaseBuilder<'_, Actor> = BaseBuilder::new(
+            self.actor_client.apify_client,
+            self.actor_client.url_segment.clone(),
+            Method::PUT,
+        );
+        builder.raw_payload(serde_json::to_vec(&self.payload)?);
+        builder.headers(crate::utils::json_content_headers());
+        builder.send().await
+    }
+}
+
+/// Writable fields of an actor
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ActorDefinition {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_public: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restart_on_error: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub versions: Option<Vec<ActorVersion>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_run_options: Option<ActorDefaultRunOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub example_run_input: Option<ActorExampleRunInput>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Actor {
+    pub id: String,
+    pub user_id: String,
+    pub name: String,
+    pub username: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub is_public: bool,
+    pub restart_on_error: Option<bool>,
+    pub created_at: String,
+    pub modified_at: String,
+    #[serde(default)]
+    pub versions: Vec<ActorVersion>,
+    #[serde(default)]
+    pub default_run_options: ActorDefaultRunOptions,
+    pub example_run_input: Option<ActorExampleRunInput>,
+    #[serde(default)]
+    pub stats: ActorStats,
+}
+
+/// Used for runs that don't set these options themselves
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ActorDefaultRunOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub build: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_secs: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory_mbytes: Option<u32>,
+}
+
+/// Prefilled input in the console, `body` is a string in the given content type
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ActorExampleRunInput {
+    pub body: String,
+    pub content_type: String,
+}
+
+#[derive(Default, Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ActorStats {
+    pub total_builds: u64,
+    pub total_runs: u64,
+    pub total_users: u64,
+    pub last_run_started_at: Option<String>,
+}
@@ -0,0 +1,77 @@
+use crate::apify_client::ApifyClient;
+use reqwest::Method;
+use serde::Deserialize;
+use crate::base_clients::resource_collection_client::ResourceCollectionClient;
+use crate::builders::list::ListBuilder;
+use crate::generic_types::BaseBuilder;
+use crate::error::ApifyClientError;
+use crate::resource_clients::actor::{Actor, ActorDefinition};
+
+pub struct ActorCollectionClient<'a> {
+    pub apify_client: &'a ApifyClient,
+    pub url_segment: String,
+}
+
+// See comment on the ResourceClient trait why this boilerplate is needed
+impl <'a> ResourceCollectionClient<'a, ActorShort> for ActorCollectionClient<'a> {
+    fn get_client(&self) -> &'a ApifyClient {
+        self.apify_client
+    }
+
+    fn get_url_segment(&self) -> &str {
+        &self.url_segment
+    }
+}
+
+impl <'a> ActorCollectionClient<'a> {
+    pub fn new(apify_client: &'a ApifyClient) -> Self {
+        ActorCollectionClient {
+            apify_client,
+            url_segment: "acts".to_owned(),
+        }
+    }
+
+    /// `name` has to be set on the definition
+    pub fn create(&self, definition: ActorDefinition) -> CreateActorBuilder {
+        CreateActorBuilder {
+            actor_collection_client: self,
+            payload: definition,
+        }
+    }
+}
+
+impl <'a> ListBuilder<'a, ActorShort> {
+    /// Only actors owned by the user, otherwise also actors the user has used
+    pub fn my(& mut self, my: bool) -> &'_ mut Self {
+        self.options.my = Some(my);
+        self
+    }
+}
+
+pub struct CreateActorBuilder<'a> {
+    actor_collection_client: &'a ActorCollectionClient<'a>,
+    payload: ActorDefinition,
+}
+
+impl <'a> CreateActorBuilder<'a> {
+    pub async fn send(self) -> Result<Actor, ApifyClientError> {
+        let mut builder: BaseBuilder<'_, Actor> = BaseBuilder::new(
+            self.actor_collection_client.apify_client,
+            self.actor_collection_client.url_segment.clone(),
+            Method::POST,
+        );
+        builder.raw_payload(serde_json::to_vec(&self.payload)?);
+        builder.headers(crate::utils::json_content_headers());
+        builder.send().await
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ActorShort {
+    pub id: String,
+    pub name: String,
+    pub username: String,
+    pub created_at: String,
+    pub modified_at: String,
+}
@@ -0,0 +1,72 @@
+use crate::apify_client::ApifyClient;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use crate::base_clients::resource_client::ResourceClient;
+use crate::generic_types::BaseBuilder;
+use crate::error::ApifyClientError;
+
+/// Environment variable of an actor version
+pub struct ActorEnvVarClient<'a> {
+    pub apify_client: &'a ApifyClient,
+    pub url_segment: String,
+}
+
+// See comment on the ResourceClient trait why this boilerplate is needed
+impl <'a> ResourceClient<'a, ActorEnvVar> for ActorEnvVarClient<'a> {
+    fn get_client(&self) -> &'a ApifyClient {
+        self.apify_client
+    }
+
+    fn get_url_segment(&self) -> &str {
+        &self.url_segment
+    }
+}
+
+impl <'a> ActorEnvVarClient<'a> {
+    /// Renaming is done by passing a different name in `env_var`
+    pub fn update(&self, env_var: ActorEnvVar) -> UpdateActorEnvVarBuilder {
+        UpdateActorEnvVarBuilder {
+            actor_env_var_client: self,
+            payload: env_var,
+        }
+    }
+}
+
+pub struct UpdateActorEnvVarBuilder<'a> {
+    actor_env_var_client: &'a ActorEnvVarClient<'a>,
+    payload: ActorEnvVar,
+}
+
+impl <'a> UpdateActorEnvVarBuilder<'a> {
+    pub async fn send(self) -> Result<ActorEnvVar, ApifyClientError> {
+        let mut builder: BaseBuilder<'_, ActorEnvVar> = BaseBuilder::new(
+            self.actor_env_var_client.apify_client,
+            self.actor_env_var_client.url_segment.clone(),
+            Method::PUT,
+        );
+        builder.raw_payload(serde_json::to_vec(&self.payload)?);
+        builder.headers(crate::utils::json_content_headers());
+        builder.send().await
+    }
+}
+
+/// Values of secret variables are not returned by the API
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ActorEnvVar {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_secret: Option<bool>,
+}
+
+impl ActorEnvVar {
+    pub fn new(name: &str, value: &str, is_secret: bool) -> Self {
+        ActorEnvVar {
+            name: name.to_owned(),
+            value: Some(value.to_owned()),
+            is_secret: Some(is_secret),
+        }
+    }
+}
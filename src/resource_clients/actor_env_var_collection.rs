@@ -0,0 +1,47 @@
+use crate::apify_client::ApifyClient;
+use reqwest::Method;
+use crate::generic_types::{BaseBuilder, UnpaginatedList};
+use crate::error::ApifyClientError;
+use crate::resource_clients::actor_env_var::ActorEnvVar;
+
+/// Environment variables of an actor version
+pub struct ActorEnvVarCollectionClient<'a> {
+    pub apify_client: &'a ApifyClient,
+    pub url_segment: String,
+}
+
+impl <'a> ActorEnvVarCollectionClient<'a> {
+    /// Environment variables are not paginated, all of them are returned at once
+    pub fn list(&self) -> BaseBuilder<'a, UnpaginatedList<ActorEnvVar>> {
+        BaseBuilder::new(
+            self.apify_client,
+            self.url_segment.clone(),
+            Method::GET,
+        )
+    }
+
+    pub fn create(&self, env_var: ActorEnvVar) -> CreateActorEnvVarBuilder {
+        CreateActorEnvVarBuilder {
+            actor_env_var_collection_client: self,
+            payload: env_var,
+        }
+    }
+}
+
+pub struct CreateActorEnvVarBuilder<'a> {
+    actor_env_var_collection_client: &'a ActorEnvVarCollectionClient<'a>,
+    payload: ActorEnvVar,
+}
+
+impl <'a> CreateActorEnvVarBuilder<'a> {
+    pub async fn send(self) -> Result<ActorEnvVar, ApifyClientError> {
+        let mut builder: BaseBuilder<'_, ActorEnvVar> = BaseBuilder::new(
+            self.actor_env_var_collection_client.apify_client,
+            self.actor_env_var_collection_client.url_segment.clone(),
+            Method::POST,
+        );
+        builder.raw_payload(serde_json::to_vec(&self.payload)?);
+        builder.headers(crate::utils::json_content_headers());
+        builder.send().await
+    }
+}
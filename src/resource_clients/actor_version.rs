@@ -0,0 +1,143 @@
+use crate::apify_client::ApifyClient;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use crate::base_clients::resource_client::ResourceClient;
+use crate::generic_types::BaseBuilder;
+use crate::error::ApifyClientError;
+use crate::utils::encode_path_segment;
+use crate::resource_clients::actor_env_var::{ActorEnvVar, ActorEnvVarClient};
+use crate::resource_clients::actor_env_var_collection::ActorEnvVarCollectionClient;
+
+pub struct ActorVersionClient<'a> {
+    pub apify_client: &'a ApifyClient,
+    pub url_segment: String,
+}
+
+// See comment on the ResourceClient trait why this boilerplate is needed
+impl <'a> ResourceClient<'a, ActorVersion> for ActorVersionClient<'a> {
+    fn get_client(&self) -> &'a ApifyClient {
+        self.apify_client
+    }
+
+    fn get_url_segment(&self) -> &str {
+        &self.url_segment
+    }
+}
+
+impl <'a> ActorVersionClient<'a> {
+    /// Only fields that are set are updated
+    pub fn update(&self, version: ActorVersionUpdate) -> UpdateActorVersionBuilder {
+        UpdateActorVersionBuilder {
+            actor_version_client: self,
+            payload: version,
+        }
+    }
+
+    pub fn env_vars(&self) -> ActorEnvVarCollectionClient<'a> {
+        ActorEnvVarCollectionClient {
+            apify_client: self.apify_client,
+            url_segment: format!("{}/env-vars", self.url_segment),
+        }
+    }
+
+    pub fn env_var(&self, name: &str) -> ActorEnvVarClient<'a> {
+        ActorEnvVarClient {
+            apify_client: self.apify_client,
+            url_segment: format!("{}/env-vars/{}", self.url_segment, encode_path_segment(name)),
+        }
+    }
+}
+
+pub struct UpdateActorVersionBuilder<'a> {
+    actor_version_client: &'a ActorVersionClient<'a>,
+    payload: ActorVersionUpdate,
+}
+
+impl <'a> UpdateActorVersionBuilder<'a> {
+    pub async fn send(self) -> Result<ActorVersion, ApifyClientError> {
+        let mut builder: BaseBuilder<'_, ActorVersion> = BaseBuilder::new(
+            self.actor_version_client.apify_client,
+            self.actor_version_client.url_segment.clone(),
+            Method::PUT,
+        );
+        builder.raw_payload(serde_json::to_vec(&self.payload)?);
+        builder.headers(crate::utils::json_content_headers());
+        builder.send().await
+    }
+}
+
+/// Version of an actor, `source` decides where the code of the version comes from
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ActorVersion {
+    /// E.g. `0.1`
+    pub version_number: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub build_tag: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub env_vars: Option<Vec<ActorEnvVar>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub apply_env_vars_to_build: Option<bool>,
+    #[serde(flatten)]
+    pub source: ActorVersionSource,
+}
+
+/// Fields of a version to update, `source` replaces the whole source of the version
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ActorVersionUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub build_tag: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub env_vars: Option<Vec<ActorEnvVar>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub apply_env_vars_to_build: Option<bool>,
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub source: Option<ActorVersionSource>,
+}
+
+/// Serialized as `sourceType` with its type specific fields next to it
+/// Source types added to the API later end up in `Unknown`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "sourceType", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ActorVersionSource {
+    #[serde(rename_all = "camelCase")]
+    SourceFiles {
+        source_files: Vec<ActorSourceFile>,
+    },
+    #[serde(rename_all = "camelCase")]
+    GitRepo {
+        /// Branch or folder can be appended as `#branch:folder`
+        git_repo_url: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    Tarball {
+        tarball_url: String,
+    },
+    GithubGist {
+        #[serde(rename = "gitHubGistUrl")]
+        github_gist_url: String,
+    },
+    /// Can't be sent back to the API, leave `source` out of the update instead
+    #[serde(other)]
+    Unknown,
+}
+
+/// File or folder of a version with the `SourceFiles` source
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ActorSourceFile {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<ActorSourceFileFormat>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub folder: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ActorSourceFileFormat {
+    Text,
+    Base64,
+}
@@ -0,0 +1,46 @@
+use crate::apify_client::ApifyClient;
+use reqwest::Method;
+use crate::generic_types::{BaseBuilder, UnpaginatedList};
+use crate::error::ApifyClientError;
+use crate::resource_clients::actor_version::ActorVersion;
+
+pub struct ActorVersionCollectionClient<'a> {
+    pub apify_client: &'a ApifyClient,
+    pub url_segment: String,
+}
+
+impl <'a> ActorVersionCollectionClient<'a> {
+    /// Versions are not paginated, all of them are returned at once
+    pub fn list(&self) -> BaseBuilder<'a, UnpaginatedList<ActorVersion>> {
+        BaseBuilder::new(
+            self.apify_client,
+            self.url_segment.clone(),
+            Method::GET,
+        )
+    }
+
+    pub fn create(&self, version: ActorVersion) -> CreateActorVersionBuilder {
+        CreateActorVersionBuilder {
+            actor_version_collection_client: self,
+            payload: version,
+        }
+    }
+}
+
+pub struct CreateActorVersionBuilder<'a> {
+    actor_version_collection_client: &'a ActorVersionCollectionClient<'a>,
+    payload: ActorVersion,
+}
+
+impl <'a> CreateActorVersionBuilder<'a> {
+    pub async fn send(self) -> Result<ActorVersion, ApifyClientError> {
+        let mut builder: BaseBuilder<'_, ActorVersion> = BaseBuilder::new(
+            self.actor_version_collection_client.apify_client,
+            self.actor_version_collection_client.url_segment.clone(),
+            Method::POST,
+        );
+        builder.raw_payload(serde_json::to_vec(&self.payload)?);
+        builder.headers(crate::utils::json_content_headers());
+        builder.send().await
+    }
+}
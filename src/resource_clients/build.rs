@@ -0,0 +1,98 @@
+use crate::apify_client::ApifyClient;
+use serde::Deserialize;
+use crate::base_clients::resource_client::ResourceClient;
+use crate::builders::run::{ActorJob, WaitForFinishBuilder};
+use crate::generic_types::BaseBuilder;
+use crate::resource_clients::log::LogClient;
+use crate::resource_clients::run::{Meta, RunStatus};
+use std::time::Duration;
+
+pub struct BuildClient<'a> {
+    pub apify_client: &'a ApifyClient,
+    pub url_segment: String,
+}
+
+// See comment on the ResourceClient trait why this boilerplate is needed
+impl <'a> ResourceClient<'a, Build> for BuildClient<'a> {
+    fn get_client(&self) -> &'a ApifyClient {
+        self.apify_client
+    }
+
+    fn get_url_segment(&self) -> &str {
+        &self.url_segment
+    }
+}
+
+impl <'a> BuildClient<'a> {
+    pub fn new(apify_client: &'a ApifyClient, identifier: &str) -> Self {
+        BuildClient {
+            apify_client,
+            url_segment: format!("actor-builds/{}", identifier),
+        }
+    }
+
+    pub fn abort(&self) -> BaseBuilder<'a, Build> {
+        BaseBuilder::new(
+            self.apify_client,
+            format!("{}/abort", self.url_segment),
+            reqwest::Method::POST,
+        )
+    }
+
+    /// Waits until the build finishes, indefinitely if `max_wait` is `None`
+    pub fn wait_for_finish(&self, max_wait: Option<Duration>) -> WaitForFinishBuilder<'a, Build> {
+        WaitForFinishBuilder::new(self.apify_client, self.url_segment.clone(), self.get_query_string(), max_wait)
+    }
+
+    pub fn log(&self) -> LogClient<'a> {
+        LogClient {
+            apify_client: self.apify_client,
+            url_segment: format!("{}/log", self.url_segment),
+            run_status: None,
+        }
+    }
+}
+
+// Builds share statuses with runs
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Build {
+    pub id: String,
+    #[serde(rename = "actId")]
+    pub actor_id: String,
+    pub user_id: String,
+    pub started_at: String,
+    pub finished_at: Option<String>,
+    pub status: RunStatus,
+    pub meta: Meta,
+    #[serde(default)]
+    pub stats: BuildStats,
+    #[serde(default)]
+    pub options: BuildOptions,
+    pub build_number: String,
+    #[serde(default)]
+    pub usage_total_usd: f64,
+}
+
+impl ActorJob for Build {
+    fn is_terminal(&self) -> bool {
+        self.status.is_terminal()
+    }
+}
+
+#[derive(Default, Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct BuildStats {
+    pub duration_millis: u64,
+    pub run_time_secs: f64,
+    pub compute_units: f64,
+}
+
+#[derive(Default, Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct BuildOptions {
+    pub use_cache: bool,
+    pub beta_packages: Option<bool>,
+    pub memory_mbytes: u32,
+    pub disk_mbytes: u32,
+}
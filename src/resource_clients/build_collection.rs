@@ -0,0 +1,45 @@
+use crate::apify_client::ApifyClient;
+use serde::Deserialize;
+use crate::base_clients::resource_collection_client::ResourceCollectionClient;
+use crate::resource_clients::run::{Meta, RunStatus};
+
+/// Builds of the user or of an actor, depending on where it was created from
+pub struct BuildCollectionClient<'a> {
+    pub apify_client: &'a ApifyClient,
+    pub url_segment: String,
+}
+
+// See comment on the ResourceClient trait why this boilerplate is needed
+impl <'a> ResourceCollectionClient<'a, BuildShort> for BuildCollectionClient<'a> {
+    fn get_client(&self) -> &'a ApifyClient {
+        self.apify_client
+    }
+
+    fn get_url_segment(&self) -> &str {
+        &self.url_segment
+    }
+}
+
+impl <'a> BuildCollectionClient<'a> {
+    pub fn new(apify_client: &'a ApifyClient) -> Self {
+        BuildCollectionClient {
+            apify_client,
+            url_segment: "actor-builds".to_owned(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BuildShort {
+    pub id: String,
+    #[serde(rename = "actId")]
+    pub actor_id: String,
+    pub status: RunStatus,
+    pub started_at: String,
+    pub finished_at: Option<String>,
+    #[serde(default)]
+    pub meta: Meta,
+    #[serde(default)]
+    pub usage_total_usd: f64,
+}
@@ -5,13 +5,16 @@ use serde::de::DeserializeOwned;
 use crate::base_clients::resource_client::ResourceClient;
 use crate::generic_types::{BaseBuilder, PaginationList, NoOutput};
 use crate::error::ApifyClientError;
-use crate::builders::dataset::{GetItemsBuilder, DownloadItemsBuilder, Format};
+use crate::resource_clients::run::RunStatus;
+use crate::utils::run_status_query_string;
+use crate::builders::dataset::{GetItemsBuilder, DownloadItemsBuilder, ListAllItemsParallelBuilder, Format};
 use std::fmt::format;
 use std::marker::PhantomData;
 
 pub struct DatasetClient<'a> {
     pub apify_client: &'a ApifyClient,
     pub url_segment: String,
+    pub(crate) run_status: Option<RunStatus>,
 }
 
 // See comment on the ResourceClient trait why this boilerplate is needed
@@ -23,13 +26,18 @@ impl <'a> ResourceClient<'a, Dataset> for DatasetClient<'a> {
     fn get_url_segment(&self) -> &str {
         &self.url_segment
     }
+
+    fn get_query_string(&self) -> String {
+        run_status_query_string(&self.run_status)
+    }
 }
 
 impl <'a> DatasetClient<'a> {
     pub fn new(apify_client: &'a ApifyClient, identifier: &str) -> Self {
         DatasetClient {
             apify_client,
-            url_segment: format!("dataset/{}", identifier),
+            url_segment: format!("datasets/{}", identifier),
+            run_status: None,
         }
     }
 
@@ -37,6 +45,11 @@ impl <'a> DatasetClient<'a> {
         GetItemsBuilder::new(self)
     }
 
+    /// Downloads the whole dataset in `chunk_size` chunks with `concurrency` requests at once
+    pub fn list_all_items_parallel<T: serde::de::DeserializeOwned>(&self, concurrency: usize, chunk_size: u64) -> ListAllItemsParallelBuilder<T> {
+        ListAllItemsParallelBuilder::new(self, concurrency, chunk_size)
+    }
+
     pub fn download_items(&self, format: Format) -> DownloadItemsBuilder {
         DownloadItemsBuilder::new(self, format)
     }
@@ -66,12 +79,9 @@ pub struct PushItemsBuilder<'a, T: serde::Serialize + 'a> {
 
 impl <'a, T: serde::Serialize> PushItemsBuilder<'a, T> {
     pub async fn send(self) -> Result<NoOutput, ApifyClientError> {
-        let mut builder: BaseBuilder<'_, NoOutput> = BaseBuilder::new(
-            self.dataset_client.apify_client,
-            self.dataset_client.url_segment.clone(),
-            Method::POST,
-        );
+        let mut builder: BaseBuilder<'_, NoOutput> = self.dataset_client.base_builder(Method::POST, "items");
         builder.raw_payload(serde_json::to_vec(&self.items)?);
+        builder.headers(crate::utils::json_content_headers());
         builder.validate_and_send_request().await?;
         Ok(NoOutput)
     }
@@ -89,12 +99,9 @@ pub struct UpdateDatasetBuilder<'a> {
 
 impl <'a> UpdateDatasetBuilder<'a> {
     pub async fn send(self) -> Result<Dataset, ApifyClientError> {
-        let mut builder: BaseBuilder<'_, Dataset> = BaseBuilder::new(
-            self.dataset_client.apify_client,
-            self.dataset_client.url_segment.clone(),
-            Method::PUT,
-        );
+        let mut builder: BaseBuilder<'_, Dataset> = self.dataset_client.base_builder(Method::PUT, "");
         builder.raw_payload(serde_json::to_vec(&self.payload)?);
+        builder.headers(crate::utils::json_content_headers());
         builder.send().await
     }
 }
@@ -1,12 +1,10 @@
 use crate::apify_client::ApifyClient;
 use reqwest::Method;
-use serde::{Deserialize, Serialize};
-use serde::de::DeserializeOwned;
-use crate::base_clients::resource_client::ResourceClient;
-use crate::generic_types::{BaseBuilder, PaginationList, NoOutput};
-use crate::error::ApifyClientError;
-use crate::builders::dataset::{GetItemsBuilder, DownloadItemsBuilder, Format};
-use std::marker::PhantomData;
+use crate::base_clients::resource_collection_client::ResourceCollectionClient;
+use crate::builders::list::ListBuilder;
+use crate::generic_types::BaseBuilder;
+use crate::utils::encode_query_value;
+use crate::resource_clients::dataset::Dataset;
 
 pub struct DatasetCollectionClient<'a> {
     pub apify_client: &'a ApifyClient,
@@ -14,7 +12,7 @@ pub struct DatasetCollectionClient<'a> {
 }
 
 // See comment on the ResourceClient trait why this boilerplate is needed
-impl <'a> ResourceClient<'a, Dataset> for DatasetCollectionClient<'a> {
+impl <'a> ResourceCollectionClient<'a, Dataset> for DatasetCollectionClient<'a> {
     fn get_client(&self) -> &'a ApifyClient {
         self.apify_client
     }
@@ -25,10 +23,28 @@ impl <'a> ResourceClient<'a, Dataset> for DatasetCollectionClient<'a> {
 }
 
 impl <'a> DatasetCollectionClient<'a> {
-    pub fn new(apify_client: &'a ApifyClient, identifier: &str) -> Self {
+    pub fn new(apify_client: &'a ApifyClient) -> Self {
         DatasetCollectionClient {
             apify_client,
-            url_segment: "dataset".to_owned(),
+            url_segment: "datasets".to_owned(),
         }
     }
-}
\ No newline at end of file
+
+    /// Returns an existing dataset with this name or creates a new one
+    pub fn get_or_create(&self, name: &str) -> BaseBuilder<'a, Dataset> {
+        let mut builder = BaseBuilder::new(
+            self.apify_client,
+            self.url_segment.clone(),
+            Method::POST,
+        );
+        builder.append_query_string(format!("name={}", encode_query_value(name)));
+        builder
+    }
+}
+
+impl <'a> ListBuilder<'a, Dataset> {
+    pub fn unnamed(& mut self, unnamed: bool) -> &'_ mut Self {
+        self.options.unnamed = Some(unnamed);
+        self
+    }
+}
@@ -0,0 +1,192 @@
+use crate::apify_client::ApifyClient;
+use reqwest::Method;
+use reqwest::header::CONTENT_TYPE;
+use serde::{Deserialize, Serialize};
+use crate::base_clients::resource_client::ResourceClient;
+use crate::generic_types::{BaseBuilder, NoOutput};
+use crate::error::ApifyClientError;
+use crate::resource_clients::run::RunStatus;
+use crate::utils::{run_status_query_string, encode_path_segment};
+use crate::builders::key_value_store::ListKeysBuilder;
+use std::marker::PhantomData;
+
+pub struct KeyValueStoreClient<'a> {
+    pub apify_client: &'a ApifyClient,
+    pub url_segment: String,
+    pub(crate) run_status: Option<RunStatus>,
+}
+
+// See comment on the ResourceClient trait why this boilerplate is needed
+impl <'a> ResourceClient<'a, KeyValueStore> for KeyValueStoreClient<'a> {
+    fn get_client(&self) -> &'a ApifyClient {
+        self.apify_client
+    }
+
+    fn get_url_segment(&self) -> &str {
+        &self.url_segment
+    }
+
+    fn get_query_string(&self) -> String {
+        run_status_query_string(&self.run_status)
+    }
+}
+
+impl <'a> KeyValueStoreClient<'a> {
+    pub fn new(apify_client: &'a ApifyClient, identifier: &str) -> Self {
+        KeyValueStoreClient {
+            apify_client,
+            url_segment: format!("key-value-stores/{}", identifier),
+            run_status: None,
+        }
+    }
+
+    pub fn update(&self, name: &str) -> UpdateKeyValueStoreBuilder {
+        UpdateKeyValueStoreBuilder {
+            key_value_store_client: self,
+            payload: UpdateKeyValueStorePayload {
+                name: name.to_owned(),
+            },
+        }
+    }
+
+    /// JSON records are deserialized into `T`, records with any other content type are returned as raw bytes
+    pub fn get_record<T: serde::de::DeserializeOwned>(&self, key: &str) -> GetRecordBuilder<T> {
+        GetRecordBuilder {
+            key_value_store_client: self,
+            key: key.to_owned(),
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn set_record<T: serde::Serialize>(&self, key: &str, value: RecordValue<T>) -> SetRecordBuilder<T> {
+        SetRecordBuilder {
+            key_value_store_client: self,
+            key: key.to_owned(),
+            value,
+            content_type: None,
+        }
+    }
+
+    pub fn list_keys(&self) -> ListKeysBuilder {
+        ListKeysBuilder::new(self)
+    }
+
+    pub fn delete_record(&self, key: &str) -> BaseBuilder<'a, NoOutput> {
+        self.base_builder(Method::DELETE, &format!("records/{}", encode_path_segment(key)))
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct UpdateKeyValueStorePayload {
+    name: String
+}
+
+pub struct UpdateKeyValueStoreBuilder<'a> {
+    key_value_store_client: &'a KeyValueStoreClient<'a>,
+    payload: UpdateKeyValueStorePayload,
+}
+
+impl <'a> UpdateKeyValueStoreBuilder<'a> {
+    pub async fn send(self) -> Result<KeyValueStore, ApifyClientError> {
+        let mut builder: BaseBuilder<'_, KeyValueStore> = self.key_value_store_client.base_builder(Method::PUT, "");
+        builder.raw_payload(serde_json::to_vec(&self.payload)?);
+        builder.headers(crate::utils::json_content_headers());
+        builder.send().await
+    }
+}
+
+pub struct GetRecordBuilder<'a, T> {
+    key_value_store_client: &'a KeyValueStoreClient<'a>,
+    key: String,
+    _phantom: PhantomData<T>,
+}
+
+impl <'a, T: serde::de::DeserializeOwned> GetRecordBuilder<'a, T> {
+    pub async fn send(self) -> Result<KeyValueStoreRecord<T>, ApifyClientError> {
+        let builder: BaseBuilder<'_, NoOutput> = self.key_value_store_client.base_builder(Method::GET, &format!("records/{}", encode_path_segment(&self.key)));
+        let resp = builder.validate_and_send_request().await?;
+        // Records are returned as they were stored, without the data wrapper
+        let content_type = resp.headers()
+            .get(CONTENT_TYPE)
+            .and_then(|header| header.to_str().ok())
+            .unwrap_or(DEFAULT_RAW_CONTENT_TYPE)
+            .to_owned();
+        let bytes = resp.bytes().await?;
+        let value = if content_type.starts_with("application/json") {
+            RecordValue::Json(serde_json::from_slice(&bytes)?)
+        } else {
+            RecordValue::Raw(bytes.to_vec())
+        };
+        Ok(KeyValueStoreRecord {
+            key: self.key,
+            value,
+            content_type,
+        })
+    }
+}
+
+pub struct SetRecordBuilder<'a, T: serde::Serialize> {
+    key_value_store_client: &'a KeyValueStoreClient<'a>,
+    key: String,
+    value: RecordValue<T>,
+    content_type: Option<String>,
+}
+
+impl <'a, T: serde::Serialize> SetRecordBuilder<'a, T> {
+    /// Overrides the default content type which is JSON for `RecordValue::Json`
+    /// and `application/octet-stream` for `RecordValue::Raw`
+    pub fn content_type(& mut self, content_type: &str) -> &'_ mut Self {
+        self.content_type = Some(content_type.to_owned());
+        self
+    }
+
+    pub async fn send(self) -> Result<NoOutput, ApifyClientError> {
+        let (payload, default_content_type) = match self.value {
+            RecordValue::Json(value) => (serde_json::to_vec(&value)?, DEFAULT_JSON_CONTENT_TYPE),
+            RecordValue::Raw(bytes) => (bytes, DEFAULT_RAW_CONTENT_TYPE),
+        };
+        let content_type = self.content_type.unwrap_or_else(|| default_content_type.to_owned());
+
+        let headers = crate::utils::content_type_headers(&content_type)?;
+
+        let mut builder: BaseBuilder<'_, NoOutput> = self.key_value_store_client.base_builder(Method::PUT, &format!("records/{}", encode_path_segment(&self.key)));
+        builder.raw_payload(payload);
+        builder.headers(headers);
+        builder.send().await
+    }
+}
+
+const DEFAULT_JSON_CONTENT_TYPE: &str = "application/json; charset=utf-8";
+const DEFAULT_RAW_CONTENT_TYPE: &str = "application/octet-stream";
+
+#[derive(Debug, PartialEq)]
+pub enum RecordValue<T> {
+    Json(T),
+    Raw(Vec<u8>),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct KeyValueStoreRecord<T> {
+    pub key: String,
+    pub value: RecordValue<T>,
+    pub content_type: String,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct KeyValueStoreKey {
+    pub key: String,
+    pub size: u64,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct KeyValueStore {
+    pub id: String,
+    pub name: Option<String>,
+    pub user_id: String,
+    pub created_at: String,
+    pub modified_at: String,
+    pub accessed_at: String,
+    pub act_id: Option<String>,
+    pub act_run_id: Option<String>
+}
@@ -0,0 +1,50 @@
+use crate::apify_client::ApifyClient;
+use reqwest::Method;
+use crate::base_clients::resource_collection_client::ResourceCollectionClient;
+use crate::builders::list::ListBuilder;
+use crate::generic_types::BaseBuilder;
+use crate::utils::encode_query_value;
+use crate::resource_clients::key_value_store::KeyValueStore;
+
+pub struct KeyValueStoreCollectionClient<'a> {
+    pub apify_client: &'a ApifyClient,
+    pub url_segment: String,
+}
+
+// See comment on the ResourceClient trait why this boilerplate is needed
+impl <'a> ResourceCollectionClient<'a, KeyValueStore> for KeyValueStoreCollectionClient<'a> {
+    fn get_client(&self) -> &'a ApifyClient {
+        self.apify_client
+    }
+
+    fn get_url_segment(&self) -> &str {
+        &self.url_segment
+    }
+}
+
+impl <'a> KeyValueStoreCollectionClient<'a> {
+    pub fn new(apify_client: &'a ApifyClient) -> Self {
+        KeyValueStoreCollectionClient {
+            apify_client,
+            url_segment: "key-value-stores".to_owned(),
+        }
+    }
+
+    /// Returns an existing store with this name or creates a new one
+    pub fn get_or_create(&self, name: &str) -> BaseBuilder<'a, KeyValueStore> {
+        let mut builder = BaseBuilder::new(
+            self.apify_client,
+            self.url_segment.clone(),
+            Method::POST,
+        );
+        builder.append_query_string(format!("name={}", encode_query_value(name)));
+        builder
+    }
+}
+
+impl <'a> ListBuilder<'a, KeyValueStore> {
+    pub fn unnamed(& mut self, unnamed: bool) -> &'_ mut Self {
+        self.options.unnamed = Some(unnamed);
+        self
+    }
+}
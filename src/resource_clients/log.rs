@@ -0,0 +1,124 @@
+use crate::apify_client::ApifyClient;
+use crate::generic_types::{BaseBuilder, NoOutput};
+use crate::error::{ApifyApiError, ApifyClientError};
+use futures::stream::{self, Stream};
+use reqwest::Method;
+use reqwest::Response;
+use std::collections::VecDeque;
+use crate::resource_clients::run::RunStatus;
+use crate::utils::run_status_query_string;
+
+/// Log of a run or a build, it is plain text so it doesn't use the ResourceClient trait
+pub struct LogClient<'a> {
+    pub apify_client: &'a ApifyClient,
+    pub url_segment: String,
+    pub(crate) run_status: Option<RunStatus>,
+}
+
+impl <'a> LogClient<'a> {
+    pub fn new(apify_client: &'a ApifyClient, identifier: &str) -> Self {
+        LogClient {
+            apify_client,
+            url_segment: format!("logs/{}", identifier),
+            run_status: None,
+        }
+    }
+
+    /// Full log as it is now
+    pub fn get(&self) -> GetLogBuilder {
+        GetLogBuilder {
+            log_client: self,
+        }
+    }
+
+    /// Log lines as they are written, the stream ends when the run or build finishes
+    pub fn stream(&self) -> StreamLogBuilder {
+        StreamLogBuilder {
+            log_client: self,
+        }
+    }
+
+    // Same as ResourceClient::base_builder, the log is plain text so it is not a ResourceClient
+    fn base_builder(&self, method: Method) -> BaseBuilder<'a, NoOutput> {
+        let mut base_builder = BaseBuilder::new(self.apify_client, self.url_segment.clone(), method);
+        base_builder.append_query_string(run_status_query_string(&self.run_status));
+        base_builder
+    }
+}
+
+pub struct GetLogBuilder<'a> {
+    log_client: &'a LogClient<'a>,
+}
+
+impl <'a> GetLogBuilder<'a> {
+    pub async fn send(&self) -> Result<String, ApifyClientError> {
+        let base_builder = self.log_client.base_builder(Method::GET);
+        let resp = base_builder.validate_and_send_request().await?;
+        let log = resp.text().await.map_err(
+            |err| ApifyApiError::ApiFailure(format!("Apify API did not return text. Something is very wrong. Please contact support@apify.com\n{}", err))
+        )?;
+        Ok(log)
+    }
+}
+
+pub struct StreamLogBuilder<'a> {
+    log_client: &'a LogClient<'a>,
+}
+
+// Response is dropped once it is fully read
+struct LogStreamState {
+    resp: Option<Response>,
+    buffer: Vec<u8>,
+    lines: VecDeque<String>,
+}
+
+impl LogStreamState {
+    fn push_line(&mut self, line: &[u8]) {
+        let line = String::from_utf8_lossy(line);
+        self.lines.push_back(line.trim_end_matches(&['\n', '\r'][..]).to_owned());
+    }
+}
+
+impl <'a> StreamLogBuilder<'a> {
+    pub async fn send(&self) -> Result<impl Stream<Item = Result<String, ApifyClientError>>, ApifyClientError> {
+        let mut base_builder = self.log_client.base_builder(Method::GET);
+        base_builder.append_query_string("stream=1".to_owned());
+        let resp = base_builder.validate_and_send_request().await?;
+
+        let state = LogStreamState {
+            resp: Some(resp),
+            buffer: Vec::new(),
+            lines: VecDeque::new(),
+        };
+        let log_stream = stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(line) = state.lines.pop_front() {
+                    return Some((Ok(line), state));
+                }
+                let resp = state.resp.as_mut()?;
+                match resp.chunk().await {
+                    Ok(Some(chunk)) => {
+                        state.buffer.extend_from_slice(&chunk);
+                        while let Some(line_end) = state.buffer.iter().position(|byte| *byte == b'\n') {
+                            let line: Vec<u8> = state.buffer.drain(..=line_end).collect();
+                            state.push_line(&line);
+                        }
+                    }
+                    Ok(None) => {
+                        state.resp = None;
+                        // Last line doesn't have to end with a newline
+                        if !state.buffer.is_empty() {
+                            let line = std::mem::take(&mut state.buffer);
+                            state.push_line(&line);
+                        }
+                    }
+                    Err(err) => {
+                        state.resp = None;
+                        return Some((Err(err.into()), state));
+                    }
+                }
+            }
+        });
+        Ok(log_stream)
+    }
+}
@@ -0,0 +1,261 @@
+use crate::apify_client::ApifyClient;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use crate::base_clients::resource_client::ResourceClient;
+use crate::generic_types::{BaseBuilder, NoOutput};
+use crate::error::ApifyClientError;
+use crate::resource_clients::run::RunStatus;
+use crate::utils::{run_status_query_string, encode_path_segment};
+use crate::builders::request_queue::{
+    AddRequestBuilder, UpdateRequestBuilder, ListHeadBuilder, BatchAddRequestsBuilder, BatchDeleteRequestsBuilder,
+    ListAndLockHeadBuilder, ProlongRequestLockBuilder, DeleteRequestLockBuilder,
+};
+use chrono::{DateTime, Utc};
+
+pub struct RequestQueueClient<'a> {
+    pub apify_client: &'a ApifyClient,
+    pub url_segment: String,
+    pub(crate) run_status: Option<RunStatus>,
+}
+
+// See comment on the ResourceClient trait why this boilerplate is needed
+impl <'a> ResourceClient<'a, RequestQueue> for RequestQueueClient<'a> {
+    fn get_client(&self) -> &'a ApifyClient {
+        self.apify_client
+    }
+
+    fn get_url_segment(&self) -> &str {
+        &self.url_segment
+    }
+
+    fn get_query_string(&self) -> String {
+        run_status_query_string(&self.run_status)
+    }
+}
+
+impl <'a> RequestQueueClient<'a> {
+    pub fn new(apify_client: &'a ApifyClient, identifier: &str) -> Self {
+        RequestQueueClient {
+            apify_client,
+            url_segment: format!("request-queues/{}", identifier),
+            run_status: None,
+        }
+    }
+
+    pub fn update(&self, name: &str) -> UpdateRequestQueueBuilder {
+        UpdateRequestQueueBuilder {
+            request_queue_client: self,
+            payload: UpdateRequestQueuePayload {
+                name: name.to_owned(),
+            },
+        }
+    }
+
+    pub fn add_request(&self, request: Request) -> AddRequestBuilder {
+        AddRequestBuilder::new(self, request)
+    }
+
+    pub fn get_request(&self, id: &str) -> BaseBuilder<'a, Request> {
+        self.base_builder(Method::GET, &format!("requests/{}", encode_path_segment(id)))
+    }
+
+    /// The request needs to have an `id`
+    pub fn update_request(&self, request: Request) -> UpdateRequestBuilder {
+        UpdateRequestBuilder::new(self, request)
+    }
+
+    pub fn delete_request(&self, id: &str) -> BaseBuilder<'a, NoOutput> {
+        self.base_builder(Method::DELETE, &format!("requests/{}", encode_path_segment(id)))
+    }
+
+    pub fn list_head(&self) -> ListHeadBuilder {
+        ListHeadBuilder::new(self)
+    }
+
+    /// Locks the returned requests for `lock_secs` so other clients don't get them from the head
+    pub fn list_and_lock_head(&self, lock_secs: u64, limit: u64) -> ListAndLockHeadBuilder {
+        ListAndLockHeadBuilder::new(self, lock_secs, limit)
+    }
+
+    pub fn prolong_request_lock(&self, id: &str, lock_secs: u64) -> ProlongRequestLockBuilder {
+        ProlongRequestLockBuilder::new(self, id, lock_secs)
+    }
+
+    pub fn delete_request_lock(&self, id: &str) -> DeleteRequestLockBuilder {
+        DeleteRequestLockBuilder::new(self, id)
+    }
+
+    /// Any number of requests is split into batches of 25 that are sent concurrently
+    pub fn batch_add_requests(&self, requests: Vec<Request>) -> BatchAddRequestsBuilder {
+        BatchAddRequestsBuilder::new(self, requests)
+    }
+
+    /// Any number of requests is split into batches of 25 that are sent concurrently
+    pub fn batch_delete_requests(&self, requests: Vec<RequestIdentifier>) -> BatchDeleteRequestsBuilder {
+        BatchDeleteRequestsBuilder::new(self, requests)
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct UpdateRequestQueuePayload {
+    name: String
+}
+
+pub struct UpdateRequestQueueBuilder<'a> {
+    request_queue_client: &'a RequestQueueClient<'a>,
+    payload: UpdateRequestQueuePayload,
+}
+
+impl <'a> UpdateRequestQueueBuilder<'a> {
+    pub async fn send(self) -> Result<RequestQueue, ApifyClientError> {
+        let mut builder: BaseBuilder<'_, RequestQueue> = self.request_queue_client.base_builder(Method::PUT, "");
+        builder.raw_payload(serde_json::to_vec(&self.payload)?);
+        builder.headers(crate::utils::json_content_headers());
+        builder.send().await
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Request {
+    // Assigned by the queue, missing when adding a new request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub url: String,
+    pub unique_key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_data: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_count: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub handled_at: Option<String>,
+}
+
+impl Request {
+    /// Creates a GET request that uses its URL as the unique key
+    pub fn new(url: &str) -> Self {
+        Request {
+            id: None,
+            url: url.to_owned(),
+            unique_key: url.to_owned(),
+            method: None,
+            payload: None,
+            user_data: None,
+            retry_count: None,
+            handled_at: None,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestQueueOperationInfo {
+    pub request_id: String,
+    pub was_already_present: bool,
+    pub was_already_handled: bool,
+}
+
+/// Identifies a request to delete, either `id` or `unique_key` needs to be set
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestIdentifier {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unique_key: Option<String>,
+}
+
+impl RequestIdentifier {
+    pub fn id(id: &str) -> Self {
+        RequestIdentifier { id: Some(id.to_owned()), unique_key: None }
+    }
+
+    pub fn unique_key(unique_key: &str) -> Self {
+        RequestIdentifier { id: None, unique_key: Some(unique_key.to_owned()) }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessedRequest {
+    pub request_id: String,
+    pub unique_key: String,
+    pub was_already_present: bool,
+    pub was_already_handled: bool,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UnprocessedRequest {
+    pub id: Option<String>,
+    pub unique_key: Option<String>,
+    pub url: Option<String>,
+    pub method: Option<String>,
+}
+
+/// Result of all batch calls of a batch operation
+#[derive(Debug)]
+pub struct BatchOperationResult<T> {
+    pub processed_requests: Vec<T>,
+    pub unprocessed_requests: Vec<UnprocessedRequest>,
+    /// Errors of batch calls that failed while other calls succeeded,
+    /// requests of the failed calls are in `unprocessed_requests`
+    pub errors: Vec<ApifyClientError>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestQueueHead {
+    pub limit: u64,
+    pub queue_modified_at: String,
+    pub had_multiple_clients: bool,
+    pub items: Vec<Request>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedRequest {
+    pub id: String,
+    pub url: String,
+    pub unique_key: String,
+    pub method: Option<String>,
+    pub retry_count: Option<u32>,
+    pub lock_expires_at: DateTime<Utc>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedRequestQueueHead {
+    pub limit: u64,
+    pub queue_modified_at: String,
+    pub had_multiple_clients: bool,
+    pub lock_secs: u64,
+    pub items: Vec<LockedRequest>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestLockInfo {
+    pub lock_expires_at: DateTime<Utc>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestQueue {
+    pub id: String,
+    pub name: Option<String>,
+    pub user_id: String,
+    pub created_at: String,
+    pub modified_at: String,
+    pub accessed_at: String,
+    pub total_request_count: u64,
+    pub handled_request_count: u64,
+    pub pending_request_count: u64,
+    pub had_multiple_clients: Option<bool>,
+    pub act_id: Option<String>,
+    pub act_run_id: Option<String>
+}
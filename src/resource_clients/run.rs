@@ -1,10 +1,20 @@
 use crate::apify_client::ApifyClient;
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer, Serialize};
 use crate::base_clients::resource_client::ResourceClient;
+use crate::builders::run::{WaitForFinishBuilder, AbortRunBuilder, ResurrectRunBuilder, MetamorphRunBuilder};
+use crate::generic_types::BaseBuilder;
+use crate::resource_clients::log::LogClient;
+use crate::resource_clients::dataset::DatasetClient;
+use crate::resource_clients::key_value_store::KeyValueStoreClient;
+use crate::resource_clients::request_queue::RequestQueueClient;
+use crate::utils::run_status_query_string;
+use std::time::Duration;
 
 pub struct RunClient<'a> {
-    apify_client: &'a ApifyClient,
-    url_segment: String,
+    pub apify_client: &'a ApifyClient,
+    pub url_segment: String,
+    /// Only set for the last run, the API then picks the last run with this status
+    pub status: Option<RunStatus>,
 }
 
 // See comment on the ResourceClient trait why this boilerplate is needed
@@ -16,6 +26,10 @@ impl <'a> ResourceClient<'a, Run> for RunClient<'a> {
     fn get_url_segment(&self) -> &str {
         &self.url_segment
     }
+
+    fn get_query_string(&self) -> String {
+        run_status_query_string(&self.status)
+    }
 }
 
 impl <'a> RunClient<'a> {
@@ -23,8 +37,77 @@ impl <'a> RunClient<'a> {
         RunClient {
             apify_client,
             url_segment: format!("actor-runs/{}", identifier),
+            status: None,
+        }
+    }
+
+    /// Last run of an actor or a task, the status filter is kept for all sub resources like the dataset
+    pub fn new_last_run(apify_client: &'a ApifyClient, parent_url_segment: &str, status: Option<RunStatus>) -> Self {
+        RunClient {
+            apify_client,
+            url_segment: format!("{}/runs/last", parent_url_segment),
+            status,
         }
     }
+
+    /// Waits until the run finishes, indefinitely if `max_wait` is `None`
+    pub fn wait_for_finish(&self, max_wait: Option<Duration>) -> WaitForFinishBuilder<'a, Run> {
+        WaitForFinishBuilder::new(self.apify_client, self.url_segment.clone(), self.get_query_string(), max_wait)
+    }
+
+    pub fn abort(&self) -> AbortRunBuilder {
+        AbortRunBuilder::new(self)
+    }
+
+    /// Restarts a finished run with the same storages
+    pub fn resurrect(&self) -> ResurrectRunBuilder {
+        ResurrectRunBuilder::new(self)
+    }
+
+    /// Transforms the run into a run of another actor with a new input
+    pub fn metamorph(&self, target_actor_id: &str) -> MetamorphRunBuilder {
+        MetamorphRunBuilder::new(self, target_actor_id)
+    }
+
+    pub fn log(&self) -> LogClient<'a> {
+        LogClient {
+            apify_client: self.apify_client,
+            url_segment: format!("{}/log", self.url_segment),
+            run_status: self.status.clone(),
+        }
+    }
+
+    /// Default dataset of the run, no need to get the run first
+    pub fn dataset(&self) -> DatasetClient<'a> {
+        DatasetClient {
+            apify_client: self.apify_client,
+            url_segment: format!("{}/dataset", self.url_segment),
+            run_status: self.status.clone(),
+        }
+    }
+
+    /// Default key-value store of the run, no need to get the run first
+    pub fn key_value_store(&self) -> KeyValueStoreClient<'a> {
+        KeyValueStoreClient {
+            apify_client: self.apify_client,
+            url_segment: format!("{}/key-value-store", self.url_segment),
+            run_status: self.status.clone(),
+        }
+    }
+
+    /// Default request queue of the run, no need to get the run first
+    pub fn request_queue(&self) -> RequestQueueClient<'a> {
+        RequestQueueClient {
+            apify_client: self.apify_client,
+            url_segment: format!("{}/request-queue", self.url_segment),
+            run_status: self.status.clone(),
+        }
+    }
+
+    /// Restarts the container of a running run
+    pub fn reboot(&self) -> BaseBuilder<'a, Run> {
+        self.base_builder(reqwest::Method::POST, "reboot")
+    }
 }
 
 // Annoying types at bottom
@@ -38,14 +121,15 @@ pub struct Run {
     pub actor_task_id: Option<String>,
     pub started_at: String,
     pub finished_at: Option<String>,
-    pub status: String,
+    pub status: RunStatus,
     pub status_message: Option<String>,
     pub is_status_message_terminal: Option<bool>,
     pub meta: Meta,
     pub stats: Stats,
     pub options: Options,
     pub build_id: String,
-    pub exit_code: u64,
+    // Missing until the run finishes
+    pub exit_code: Option<i64>,
     pub default_key_value_store_id: String,
     pub default_dataset_id: String,
     pub default_request_queue_id: String,
@@ -53,13 +137,78 @@ pub struct Run {
     pub container_url: String,
     pub is_container_server_ready: Option<bool>,
     pub git_branch_name: Option<String>,
+    // Usage is not always present on a run that just started
+    #[serde(default)]
     pub usage: Usage,
+    #[serde(default)]
     pub usage_total_usd: f64,
+    #[serde(default)]
     pub usage_usd: UsageUsd,
 }
 
+/// Status of a run, statuses added to the API later end up in `Unknown`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(from = "String", into = "String")]
+pub enum RunStatus {
+    Ready,
+    Running,
+    Succeeded,
+    Failed,
+    TimingOut,
+    TimedOut,
+    Aborting,
+    Aborted,
+    Unknown(String),
+}
+
+impl RunStatus {
+    /// Run in a terminal status will not change its status anymore
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, RunStatus::Succeeded | RunStatus::Failed | RunStatus::TimedOut | RunStatus::Aborted)
+    }
+}
+
+impl From<String> for RunStatus {
+    fn from(status: String) -> Self {
+        match status.as_str() {
+            "READY" => RunStatus::Ready,
+            "RUNNING" => RunStatus::Running,
+            "SUCCEEDED" => RunStatus::Succeeded,
+            "FAILED" => RunStatus::Failed,
+            "TIMING-OUT" => RunStatus::TimingOut,
+            "TIMED-OUT" => RunStatus::TimedOut,
+            "ABORTING" => RunStatus::Aborting,
+            "ABORTED" => RunStatus::Aborted,
+            _ => RunStatus::Unknown(status),
+        }
+    }
+}
+
+impl From<RunStatus> for String {
+    fn from(status: RunStatus) -> Self {
+        status.to_string()
+    }
+}
+
+impl std::fmt::Display for RunStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        let string_repr = match self {
+            RunStatus::Ready => "READY",
+            RunStatus::Running => "RUNNING",
+            RunStatus::Succeeded => "SUCCEEDED",
+            RunStatus::Failed => "FAILED",
+            RunStatus::TimingOut => "TIMING-OUT",
+            RunStatus::TimedOut => "TIMED-OUT",
+            RunStatus::Aborting => "ABORTING",
+            RunStatus::Aborted => "ABORTED",
+            RunStatus::Unknown(status) => status,
+        };
+        write!(f, "{}", string_repr)
+    }
+}
+
 #[derive(Default, Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(rename_all = "camelCase", default)]
 pub struct Meta {
     pub origin: String,
     // Only when run was started via API
@@ -68,7 +217,7 @@ pub struct Meta {
     
 }
 #[derive(Default, Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(rename_all = "camelCase", default)]
 pub struct Stats {
     pub input_body_len: u64,
     pub reboot_count: u32,
@@ -97,24 +246,30 @@ pub struct Options {
     pub disk_mbytes: u32,
 }
 
+// Counts can come as floats, e.g. `3.0` in the monthly usage of the account
+fn deserialize_count<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    let count = f64::deserialize(deserializer)?;
+    Ok(count.round() as u64)
+}
+
 #[derive(Default, Debug, Clone,  Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(rename_all = "camelCase", default)]
 pub struct Usage {
     #[serde(rename = "ACTOR_COMPUTE_UNITS")]
     pub actor_compute_units: f64,
-    #[serde(rename = "DATASET_READS")]
+    #[serde(rename = "DATASET_READS", deserialize_with = "deserialize_count")]
     pub dataset_reads: u64,
-    #[serde(rename = "DATASET_WRITES")]
+    #[serde(rename = "DATASET_WRITES", deserialize_with = "deserialize_count")]
     pub dataset_writes: u64,
-    #[serde(rename = "KEY_VALUE_STORE_READS")]
+    #[serde(rename = "KEY_VALUE_STORE_READS", deserialize_with = "deserialize_count")]
     pub key_value_store_reads: u64,
-    #[serde(rename = "KEY_VALUE_STORE_WRITES")]
+    #[serde(rename = "KEY_VALUE_STORE_WRITES", deserialize_with = "deserialize_count")]
     pub key_value_store_writes: u64,
-    #[serde(rename = "KEY_VALUE_STORE_LISTS")]
+    #[serde(rename = "KEY_VALUE_STORE_LISTS", deserialize_with = "deserialize_count")]
     pub key_value_store_lists: u64,
-    #[serde(rename = "REQUEST_QUEUE_READS")]
+    #[serde(rename = "REQUEST_QUEUE_READS", deserialize_with = "deserialize_count")]
     pub request_queue_reads: u64,
-    #[serde(rename = "REQUEST_QUEUE_WRITES")]
+    #[serde(rename = "REQUEST_QUEUE_WRITES", deserialize_with = "deserialize_count")]
     pub request_queue_writes: u64,
     #[serde(rename = "DATA_TRANSFER_INTERNAL_GBYTES")]
     pub data_transfer_internal_gbytes: f64,
@@ -122,12 +277,12 @@ pub struct Usage {
     pub data_transfer_external_gbytes: f64,
     #[serde(rename = "PROXY_RESIDENTIAL_TRANSFER_GBYTES")]
     pub proxy_residential_transfer_gbytes: f64,
-    #[serde(rename = "PROXY_SERPS")]
+    #[serde(rename = "PROXY_SERPS", deserialize_with = "deserialize_count")]
     pub proxy_serps: u64,
 }
 
 #[derive(Default, Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(rename_all = "camelCase", default)]
 pub struct UsageUsd {
     #[serde(rename = "ACTOR_COMPUTE_UNITS")]
     pub actor_compute_units: f64,
@@ -0,0 +1,66 @@
+use crate::apify_client::ApifyClient;
+use serde::Deserialize;
+use crate::base_clients::resource_collection_client::ResourceCollectionClient;
+use crate::builders::list::ListBuilder;
+use crate::resource_clients::run::RunStatus;
+
+/// Runs of the user, an actor or a task, depending on where it was created from
+pub struct RunCollectionClient<'a> {
+    pub apify_client: &'a ApifyClient,
+    pub url_segment: String,
+}
+
+// See comment on the ResourceClient trait why this boilerplate is needed
+impl <'a> ResourceCollectionClient<'a, RunShort> for RunCollectionClient<'a> {
+    fn get_client(&self) -> &'a ApifyClient {
+        self.apify_client
+    }
+
+    fn get_url_segment(&self) -> &str {
+        &self.url_segment
+    }
+}
+
+impl <'a> RunCollectionClient<'a> {
+    pub fn new(apify_client: &'a ApifyClient) -> Self {
+        RunCollectionClient {
+            apify_client,
+            url_segment: "actor-runs".to_owned(),
+        }
+    }
+}
+
+impl <'a> ListBuilder<'a, RunShort> {
+    /// Only runs with this status are listed
+    pub fn status(& mut self, status: RunStatus) -> &'_ mut Self {
+        self.options.status = Some(status.to_string());
+        self
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunShort {
+    pub id: String,
+    #[serde(rename = "actId")]
+    pub actor_id: String,
+    pub actor_task_id: Option<String>,
+    pub status: RunStatus,
+    pub started_at: String,
+    pub finished_at: Option<String>,
+    pub build_id: String,
+    pub build_number: String,
+    pub meta: RunShortMeta,
+    #[serde(default)]
+    pub usage_total_usd: f64,
+    pub default_key_value_store_id: String,
+    pub default_dataset_id: String,
+    pub default_request_queue_id: String,
+}
+
+#[derive(Default, Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RunShortMeta {
+    pub origin: String,
+    pub user_agent: Option<String>,
+}
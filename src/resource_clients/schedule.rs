@@ -0,0 +1,168 @@
+use crate::apify_client::ApifyClient;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use crate::base_clients::resource_client::ResourceClient;
+use crate::generic_types::BaseBuilder;
+use crate::error::ApifyClientError;
+
+pub struct ScheduleClient<'a> {
+    pub apify_client: &'a ApifyClient,
+    pub url_segment: String,
+}
+
+// See comment on the ResourceClient trait why this boilerplate is needed
+impl <'a> ResourceClient<'a, Schedule> for ScheduleClient<'a> {
+    fn get_client(&self) -> &'a ApifyClient {
+        self.apify_client
+    }
+
+    fn get_url_segment(&self) -> &str {
+        &self.url_segment
+    }
+}
+
+impl <'a> ScheduleClient<'a> {
+    pub fn new(apify_client: &'a ApifyClient, identifier: &str) -> Self {
+        ScheduleClient {
+            apify_client,
+            url_segment: format!("schedules/{}", identifier),
+        }
+    }
+
+    /// Only fields that are set on the definition are updated, `actions` are replaced as a whole
+    pub fn update(&self, definition: ScheduleDefinition) -> UpdateScheduleBuilder {
+        UpdateScheduleBuilder {
+            schedule_client: self,
+            payload: definition,
+        }
+    }
+
+    /// Messages about invoked actions of the schedule
+    pub fn get_log(&self) -> BaseBuilder<'a, Vec<ScheduleLogEntry>> {
+        BaseBuilder::new(
+            self.apify_client,
+            format!("{}/log", self.url_segment),
+            Method::GET,
+        )
+    }
+}
+
+pub struct UpdateScheduleBuilder<'a> {
+    schedule_client: &'a ScheduleClient<'a>,
+    payload: ScheduleDefinition,
+}
+
+impl <'a> UpdateScheduleBuilder<'a> {
+    pub async fn send(self) -> Result<Schedule, ApifyClientError> {
+        let mut builder: BaseBuilder<'_, Schedule> = BaseBuilder::new(
+            self.schedule_client.apify_client,
+            self.schedule_client.url_segment.clone(),
+            Method::PUT,
+        );
+        builder.raw_payload(serde_json::to_vec(&self.payload)?);
+        builder.headers(crate::utils::json_content_headers());
+        builder.send().await
+    }
+}
+
+/// Writable fields of a schedule
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleDefinition {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// E.g. `0 8 * * MON-FRI` or `@daily`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cron_expression: Option<String>,
+    /// IANA name, e.g. `Europe/Prague`, defaults to `UTC`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_enabled: Option<bool>,
+    /// Skips the invocation if the previously started run is still running
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_exclusive: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actions: Option<Vec<ScheduleAction>>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Schedule {
+    pub id: String,
+    pub user_id: String,
+    pub name: String,
+    pub cron_expression: String,
+    pub timezone: String,
+    pub is_enabled: bool,
+    pub is_exclusive: bool,
+    pub description: Option<String>,
+    pub created_at: String,
+    pub modified_at: String,
+    pub next_run_at: Option<String>,
+    pub last_run_at: Option<String>,
+    #[serde(default)]
+    pub actions: Vec<ScheduleAction>,
+}
+
+/// What the schedule starts, `id` is assigned by the API
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ScheduleAction {
+    #[serde(rename_all = "camelCase")]
+    RunActor {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        id: Option<String>,
+        actor_id: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        run_input: Option<ScheduleRunInput>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        run_options: Option<ScheduleRunOptions>,
+    },
+    #[serde(rename_all = "camelCase")]
+    RunActorTask {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        id: Option<String>,
+        actor_task_id: String,
+        /// Overrides fields of the input saved on the task
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        input: Option<serde_json::Value>,
+    },
+}
+
+/// Input of the actor, `body` is a string in the given content type
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleRunInput {
+    pub body: String,
+    pub content_type: String,
+}
+
+impl ScheduleRunInput {
+    pub fn json(input: &serde_json::Value) -> Self {
+        ScheduleRunInput {
+            body: input.to_string(),
+            content_type: "application/json; charset=utf-8".to_owned(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleRunOptions {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub build: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_secs: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory_mbytes: Option<u32>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleLogEntry {
+    pub message: String,
+    pub created_at: String,
+}
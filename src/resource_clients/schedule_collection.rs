@@ -0,0 +1,57 @@
+use crate::apify_client::ApifyClient;
+use reqwest::Method;
+use crate::base_clients::resource_collection_client::ResourceCollectionClient;
+use crate::generic_types::BaseBuilder;
+use crate::error::ApifyClientError;
+use crate::resource_clients::schedule::{Schedule, ScheduleDefinition};
+
+pub struct ScheduleCollectionClient<'a> {
+    pub apify_client: &'a ApifyClient,
+    pub url_segment: String,
+}
+
+// See comment on the ResourceClient trait why this boilerplate is needed
+impl <'a> ResourceCollectionClient<'a, Schedule> for ScheduleCollectionClient<'a> {
+    fn get_client(&self) -> &'a ApifyClient {
+        self.apify_client
+    }
+
+    fn get_url_segment(&self) -> &str {
+        &self.url_segment
+    }
+}
+
+impl <'a> ScheduleCollectionClient<'a> {
+    pub fn new(apify_client: &'a ApifyClient) -> Self {
+        ScheduleCollectionClient {
+            apify_client,
+            url_segment: "schedules".to_owned(),
+        }
+    }
+
+    /// `cron_expression` has to be set on the definition
+    pub fn create(&self, definition: ScheduleDefinition) -> CreateScheduleBuilder {
+        CreateScheduleBuilder {
+            schedule_collection_client: self,
+            payload: definition,
+        }
+    }
+}
+
+pub struct CreateScheduleBuilder<'a> {
+    schedule_collection_client: &'a ScheduleCollectionClient<'a>,
+    payload: ScheduleDefinition,
+}
+
+impl <'a> CreateScheduleBuilder<'a> {
+    pub async fn send(self) -> Result<Schedule, ApifyClientError> {
+        let mut builder: BaseBuilder<'_, Schedule> = BaseBuilder::new(
+            self.schedule_collection_client.apify_client,
+            self.schedule_collection_client.url_segment.clone(),
+            Method::POST,
+        );
+        builder.raw_payload(serde_json::to_vec(&self.payload)?);
+        builder.headers(crate::utils::json_content_headers());
+        builder.send().await
+    }
+}
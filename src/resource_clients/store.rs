@@ -0,0 +1,130 @@
+use crate::apify_client::ApifyClient;
+use serde::{Deserialize, Serialize};
+use crate::builders::store::StoreListBuilder;
+
+/// Public actors in Apify Store
+pub struct StoreCollectionClient<'a> {
+    pub apify_client: &'a ApifyClient,
+    pub url_segment: String,
+}
+
+impl <'a> StoreCollectionClient<'a> {
+    pub fn new(apify_client: &'a ApifyClient) -> Self {
+        StoreCollectionClient {
+            apify_client,
+            url_segment: "store".to_owned(),
+        }
+    }
+
+    /// Store has its own filters so it doesn't use the shared ListBuilder
+    pub fn list(&self) -> StoreListBuilder<'a> {
+        StoreListBuilder::new(self.apify_client, self.url_segment.clone())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StoreSortBy {
+    Relevance,
+    Popularity,
+    Newest,
+    LastUpdate,
+}
+
+impl std::fmt::Display for StoreSortBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        let string_repr = match self {
+            StoreSortBy::Relevance => "relevance",
+            StoreSortBy::Popularity => "popularity",
+            StoreSortBy::Newest => "newest",
+            StoreSortBy::LastUpdate => "lastUpdate",
+        };
+        write!(f, "{}", string_repr)
+    }
+}
+
+/// How the actor is charged, pricing models added to the API later end up in `Unknown`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(from = "String", into = "String")]
+pub enum PricingModel {
+    Free,
+    FlatPricePerMonth,
+    PricePerDatasetItem,
+    PayPerEvent,
+    Unknown(String),
+}
+
+impl From<String> for PricingModel {
+    fn from(pricing_model: String) -> Self {
+        match pricing_model.as_str() {
+            "FREE" => PricingModel::Free,
+            "FLAT_PRICE_PER_MONTH" => PricingModel::FlatPricePerMonth,
+            "PRICE_PER_DATASET_ITEM" => PricingModel::PricePerDatasetItem,
+            "PAY_PER_EVENT" => PricingModel::PayPerEvent,
+            _ => PricingModel::Unknown(pricing_model),
+        }
+    }
+}
+
+impl From<PricingModel> for String {
+    fn from(pricing_model: PricingModel) -> Self {
+        pricing_model.to_string()
+    }
+}
+
+impl std::fmt::Display for PricingModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        let string_repr = match self {
+            PricingModel::Free => "FREE",
+            PricingModel::FlatPricePerMonth => "FLAT_PRICE_PER_MONTH",
+            PricingModel::PricePerDatasetItem => "PRICE_PER_DATASET_ITEM",
+            PricingModel::PayPerEvent => "PAY_PER_EVENT",
+            PricingModel::Unknown(pricing_model) => pricing_model,
+        };
+        write!(f, "{}", string_repr)
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct StoreActor {
+    pub id: String,
+    pub name: String,
+    pub username: String,
+    pub title: Option<String>,
+    pub user_full_name: Option<String>,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub categories: Vec<String>,
+    pub notice: Option<String>,
+    pub picture_url: Option<String>,
+    pub url: Option<String>,
+    #[serde(default)]
+    pub stats: StoreActorStats,
+    pub current_pricing_info: Option<PricingInfo>,
+}
+
+#[derive(Default, Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct StoreActorStats {
+    pub total_builds: u64,
+    pub total_runs: u64,
+    pub total_users: u64,
+    #[serde(rename = "totalUsers7Days")]
+    pub total_users_7_days: u64,
+    #[serde(rename = "totalUsers30Days")]
+    pub total_users_30_days: u64,
+    #[serde(rename = "totalUsers90Days")]
+    pub total_users_90_days: u64,
+    pub last_run_started_at: Option<String>,
+}
+
+/// Only the fields of the given pricing model are set
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PricingInfo {
+    pub pricing_model: PricingModel,
+    pub price_per_unit_usd: Option<f64>,
+    pub unit_name: Option<String>,
+    pub trial_minutes: Option<u64>,
+    pub started_at: Option<String>,
+}
@@ -0,0 +1,179 @@
+use crate::apify_client::ApifyClient;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use crate::base_clients::resource_client::ResourceClient;
+use crate::generic_types::BaseBuilder;
+use crate::error::ApifyClientError;
+use crate::builders::run::StartRunBuilder;
+use crate::resource_clients::run::{RunClient, RunStatus};
+use crate::resource_clients::run_collection::RunCollectionClient;
+
+pub struct TaskClient<'a> {
+    pub apify_client: &'a ApifyClient,
+    pub url_segment: String,
+}
+
+// See comment on the ResourceClient trait why this boilerplate is needed
+impl <'a> ResourceClient<'a, Task> for TaskClient<'a> {
+    fn get_client(&self) -> &'a ApifyClient {
+        self.apify_client
+    }
+
+    fn get_url_segment(&self) -> &str {
+        &self.url_segment
+    }
+}
+
+impl <'a> TaskClient<'a> {
+    pub fn new(apify_client: &'a ApifyClient, identifier: &str) -> Self {
+        TaskClient {
+            apify_client,
+            url_segment: format!("actor-tasks/{}", identifier),
+        }
+    }
+
+    /// Only fields that are set on the definition are updated
+    pub fn update(&self, definition: TaskDefinition) -> UpdateTaskBuilder {
+        UpdateTaskBuilder {
+            task_client: self,
+            payload: definition,
+        }
+    }
+
+    /// Starts a run and returns it right away without waiting for it to finish
+    /// Input passed to the builder is merged with the input saved on the task
+    pub fn start(&self) -> StartRunBuilder<'a> {
+        StartRunBuilder::new(self.apify_client, format!("{}/runs", self.url_segment), false)
+    }
+
+    /// Starts a run and waits until it finishes
+    pub fn call(&self) -> StartRunBuilder<'a> {
+        StartRunBuilder::new(self.apify_client, format!("{}/runs", self.url_segment), true)
+    }
+
+    pub fn get_input(&self) -> TaskInputBuilder {
+        TaskInputBuilder {
+            task_client: self,
+            input: None,
+        }
+    }
+
+    /// Replaces the whole input saved on the task
+    pub fn update_input(&self, input: serde_json::Value) -> TaskInputBuilder {
+        TaskInputBuilder {
+            task_client: self,
+            input: Some(input),
+        }
+    }
+
+    /// Last run of the task, optionally only from runs with the given status
+    pub fn last_run(&self, status: Option<RunStatus>) -> RunClient<'a> {
+        RunClient::new_last_run(self.apify_client, &self.url_segment, status)
+    }
+
+    pub fn runs(&self) -> RunCollectionClient<'a> {
+        RunCollectionClient {
+            apify_client: self.apify_client,
+            url_segment: format!("{}/runs", self.url_segment),
+        }
+    }
+}
+
+pub struct UpdateTaskBuilder<'a> {
+    task_client: &'a TaskClient<'a>,
+    payload: TaskDefinition,
+}
+
+impl <'a> UpdateTaskBuilder<'a> {
+    pub async fn send(self) -> Result<Task, ApifyClientError> {
+        let mut builder: BaseBuilder<'_, Task> = BaseBuilder::new(
+            self.task_client.apify_client,
+            self.task_client.url_segment.clone(),
+            Method::PUT,
+        );
+        builder.raw_payload(serde_json::to_vec(&self.payload)?);
+        builder.headers(crate::utils::json_content_headers());
+        builder.send().await
+    }
+}
+
+/// Gets the input or updates it if `input` is set
+pub struct TaskInputBuilder<'a> {
+    task_client: &'a TaskClient<'a>,
+    input: Option<serde_json::Value>,
+}
+
+impl <'a> TaskInputBuilder<'a> {
+    pub async fn send(self) -> Result<serde_json::Value, ApifyClientError> {
+        let method = if self.input.is_some() { Method::PUT } else { Method::GET };
+        let mut builder: BaseBuilder<'_, serde_json::Value> = BaseBuilder::new(
+            self.task_client.apify_client,
+            format!("{}/input", self.task_client.url_segment),
+            method,
+        );
+        if let Some(input) = &self.input {
+            builder.raw_payload(serde_json::to_vec(input)?);
+            builder.headers(crate::utils::json_content_headers());
+        }
+        // Input is returned as it was stored, without the data wrapper
+        let resp = builder.validate_and_send_request().await?;
+        let bytes = resp.bytes().await?;
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+}
+
+/// Writable fields of a task, `actor_id` and `name` are required to create one
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskDefinition {
+    #[serde(rename = "actId", skip_serializing_if = "Option::is_none")]
+    pub actor_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<TaskOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input: Option<serde_json::Value>,
+}
+
+/// Default run options of a task, same as the options of a single run
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub build: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_secs: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory_mbytes: Option<u32>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Task {
+    pub id: String,
+    #[serde(rename = "actId")]
+    pub actor_id: String,
+    pub user_id: String,
+    pub name: String,
+    pub username: Option<String>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub created_at: String,
+    pub modified_at: String,
+    #[serde(default)]
+    pub options: TaskOptions,
+    pub input: Option<serde_json::Value>,
+    #[serde(default)]
+    pub stats: TaskStats,
+}
+
+#[derive(Default, Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TaskStats {
+    pub total_runs: u64,
+}
@@ -0,0 +1,75 @@
+use crate::apify_client::ApifyClient;
+use reqwest::Method;
+use serde::Deserialize;
+use crate::base_clients::resource_collection_client::ResourceCollectionClient;
+use crate::generic_types::BaseBuilder;
+use crate::error::ApifyClientError;
+use crate::resource_clients::task::{Task, TaskDefinition, TaskStats};
+
+pub struct TaskCollectionClient<'a> {
+    pub apify_client: &'a ApifyClient,
+    pub url_segment: String,
+}
+
+// See comment on the ResourceClient trait why this boilerplate is needed
+impl <'a> ResourceCollectionClient<'a, TaskShort> for TaskCollectionClient<'a> {
+    fn get_client(&self) -> &'a ApifyClient {
+        self.apify_client
+    }
+
+    fn get_url_segment(&self) -> &str {
+        &self.url_segment
+    }
+}
+
+impl <'a> TaskCollectionClient<'a> {
+    pub fn new(apify_client: &'a ApifyClient) -> Self {
+        TaskCollectionClient {
+            apify_client,
+            url_segment: "actor-tasks".to_owned(),
+        }
+    }
+
+    /// `actor_id` and `name` have to be set on the definition
+    pub fn create(&self, definition: TaskDefinition) -> CreateTaskBuilder {
+        CreateTaskBuilder {
+            task_collection_client: self,
+            payload: definition,
+        }
+    }
+}
+
+pub struct CreateTaskBuilder<'a> {
+    task_collection_client: &'a TaskCollectionClient<'a>,
+    payload: TaskDefinition,
+}
+
+impl <'a> CreateTaskBuilder<'a> {
+    pub async fn send(self) -> Result<Task, ApifyClientError> {
+        let mut builder: BaseBuilder<'_, Task> = BaseBuilder::new(
+            self.task_collection_client.apify_client,
+            self.task_collection_client.url_segment.clone(),
+            Method::POST,
+        );
+        builder.raw_payload(serde_json::to_vec(&self.payload)?);
+        builder.headers(crate::utils::json_content_headers());
+        builder.send().await
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskShort {
+    pub id: String,
+    #[serde(rename = "actId")]
+    pub actor_id: String,
+    pub act_name: Option<String>,
+    pub act_username: Option<String>,
+    pub user_id: String,
+    pub name: String,
+    pub username: Option<String>,
+    pub created_at: String,
+    pub modified_at: String,
+    #[serde(default)]
+    pub stats: TaskStats,
+}
@@ -0,0 +1,272 @@
+use crate::apify_client::ApifyClient;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use crate::generic_types::{BaseBuilder, NoOutput};
+use crate::error::ApifyClientError;
+use crate::resource_clients::run::{Usage, UsageUsd};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+/// Account of a user, only `me` can be used for usage and limits
+pub struct UserClient<'a> {
+    pub apify_client: &'a ApifyClient,
+    pub url_segment: String,
+}
+
+impl <'a> UserClient<'a> {
+    pub fn new(apify_client: &'a ApifyClient, identifier: &str) -> Self {
+        UserClient {
+            apify_client,
+            url_segment: format!("users/{}", identifier),
+        }
+    }
+
+    /// Private fields like email, proxy and plan are only returned for `me`
+    pub fn get(&self) -> BaseBuilder<'a, User> {
+        BaseBuilder::new(
+            self.apify_client,
+            self.url_segment.clone(),
+            Method::GET,
+        )
+    }
+
+    /// Usage of the current usage cycle unless a date from another cycle is set
+    pub fn monthly_usage(&self) -> MonthlyUsageBuilder {
+        MonthlyUsageBuilder {
+            user_client: self,
+            options: Default::default(),
+        }
+    }
+
+    pub fn limits(&self) -> BaseBuilder<'a, AccountLimits> {
+        BaseBuilder::new(
+            self.apify_client,
+            format!("{}/limits", self.url_segment),
+            Method::GET,
+        )
+    }
+
+    /// Only limits that are set are updated
+    pub fn update_limits(&self, limits: LimitsUpdate) -> UpdateLimitsBuilder {
+        UpdateLimitsBuilder {
+            user_client: self,
+            payload: limits,
+        }
+    }
+}
+
+#[derive(Default, QueryParams)]
+pub struct MonthlyUsageParams {
+    date: Option<String>,
+}
+
+pub struct MonthlyUsageBuilder<'a> {
+    user_client: &'a UserClient<'a>,
+    options: MonthlyUsageParams,
+}
+
+impl <'a> MonthlyUsageBuilder<'a> {
+    pub async fn send(&self) -> Result<MonthlyUsage, ApifyClientError> {
+        let mut builder: BaseBuilder<'_, MonthlyUsage> = BaseBuilder::new(
+            self.user_client.apify_client,
+            format!("{}/usage/monthly", self.user_client.url_segment),
+            Method::GET,
+        );
+        builder.append_query_string(self.options.to_query_params());
+        builder.send().await
+    }
+
+    /// Any day in the usage cycle in `YYYY-MM-DD` format
+    pub fn date(& mut self, date: &str) -> &'_ mut Self {
+        self.options.date = Some(date.to_owned());
+        self
+    }
+}
+
+pub struct UpdateLimitsBuilder<'a> {
+    user_client: &'a UserClient<'a>,
+    payload: LimitsUpdate,
+}
+
+impl <'a> UpdateLimitsBuilder<'a> {
+    pub async fn send(self) -> Result<NoOutput, ApifyClientError> {
+        let mut builder: BaseBuilder<'_, NoOutput> = BaseBuilder::new(
+            self.user_client.apify_client,
+            format!("{}/limits", self.user_client.url_segment),
+            Method::PUT,
+        );
+        builder.raw_payload(serde_json::to_vec(&self.payload)?);
+        builder.headers(crate::utils::json_content_headers());
+        builder.send().await
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct User {
+    pub id: String,
+    pub username: String,
+    #[serde(default)]
+    pub profile: UserProfile,
+    pub email: Option<String>,
+    pub proxy: Option<UserProxy>,
+    pub plan: Option<UserPlan>,
+    pub created_at: Option<String>,
+    pub is_paying: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct UserProfile {
+    pub name: Option<String>,
+    pub bio: Option<String>,
+    pub picture_url: Option<String>,
+    pub github_username: Option<String>,
+    pub website_url: Option<String>,
+    pub twitter_username: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct UserProxy {
+    pub password: String,
+    pub groups: Vec<ProxyGroup>,
+}
+
+#[derive(Default, Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ProxyGroup {
+    pub name: String,
+    pub description: String,
+    pub available_count: u64,
+}
+
+#[derive(Default, Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct UserPlan {
+    pub id: String,
+    pub description: String,
+    pub is_enabled: bool,
+    pub monthly_base_price_usd: f64,
+    pub monthly_usage_credits_usd: f64,
+    pub usage_discount_percent: f64,
+    pub enabled_platform_features: Vec<String>,
+    pub max_monthly_usage_usd: f64,
+    pub max_actor_memory_gbytes: f64,
+    pub max_monthly_actor_compute_units: f64,
+    pub max_monthly_residential_proxy_gbytes: f64,
+    pub max_monthly_proxy_serps: u64,
+    pub max_monthly_external_data_transfer_gbytes: f64,
+    pub max_actor_count: u64,
+    pub max_actor_task_count: u64,
+    pub data_retention_days: u64,
+    pub team_account_seat_count: u64,
+    pub support_level: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageCycle {
+    pub start_at: String,
+    pub end_at: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MonthlyUsage {
+    pub usage_cycle: UsageCycle,
+    pub monthly_service_usage: ServiceUsage,
+    pub daily_service_usages: Vec<DailyServiceUsage>,
+    pub total_usage_credits_usd_before_volume_discount: f64,
+    pub total_usage_credits_usd_after_volume_discount: f64,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DailyServiceUsage {
+    pub date: String,
+    pub service_usage: ServiceUsage,
+    pub total_usage_credits_usd: f64,
+}
+
+/// Usage per service in the same shape as the usage of a run
+/// The API returns it keyed by service, e.g. `{ "DATASET_READS": { "quantity": 10, "baseAmountUsd": 0.004 } }`
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(try_from = "HashMap<String, ServiceUsageItem>")]
+pub struct ServiceUsage {
+    pub usage: Usage,
+    pub usage_usd: UsageUsd,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ServiceUsageItem {
+    pub quantity: f64,
+    pub base_amount_usd: f64,
+}
+
+impl TryFrom<HashMap<String, ServiceUsageItem>> for ServiceUsage {
+    type Error = serde_json::Error;
+
+    fn try_from(items: HashMap<String, ServiceUsageItem>) -> Result<Self, Self::Error> {
+        let mut quantities = serde_json::Map::new();
+        let mut amounts_usd = serde_json::Map::new();
+        for (service, item) in items {
+            quantities.insert(service.clone(), serde_json::json!(item.quantity));
+            amounts_usd.insert(service, serde_json::json!(item.base_amount_usd));
+        }
+        Ok(ServiceUsage {
+            usage: serde_json::from_value(serde_json::Value::Object(quantities))?,
+            usage_usd: serde_json::from_value(serde_json::Value::Object(amounts_usd))?,
+        })
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountLimits {
+    pub monthly_usage_cycle: UsageCycle,
+    pub limits: Limits,
+    pub current: CurrentUsage,
+}
+
+#[derive(Default, Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Limits {
+    pub max_monthly_usage_usd: f64,
+    pub max_monthly_actor_compute_units: f64,
+    pub max_monthly_external_data_transfer_gbytes: f64,
+    pub max_monthly_proxy_serps: u64,
+    pub max_monthly_residential_proxy_gbytes: f64,
+    pub max_actor_memory_gbytes: f64,
+    pub max_actor_count: u64,
+    pub max_actor_task_count: u64,
+    pub max_concurrent_actor_jobs: u64,
+    pub max_team_account_seat_count: u64,
+    pub data_retention_days: u64,
+}
+
+#[derive(Default, Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CurrentUsage {
+    pub monthly_usage_usd: f64,
+    pub monthly_actor_compute_units: f64,
+    pub monthly_external_data_transfer_gbytes: f64,
+    pub monthly_proxy_serps: u64,
+    pub monthly_residential_proxy_gbytes: f64,
+    pub actor_memory_gbytes: f64,
+    pub actor_count: u64,
+    pub actor_task_count: u64,
+    pub active_actor_job_count: u64,
+    pub team_account_seat_count: u64,
+}
+
+/// Limits that can be changed by the user
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LimitsUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_monthly_usage_usd: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_retention_days: Option<u64>,
+}
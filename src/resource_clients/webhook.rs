@@ -0,0 +1,209 @@
+use crate::apify_client::ApifyClient;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use crate::base_clients::resource_client::ResourceClient;
+use crate::generic_types::BaseBuilder;
+use crate::error::ApifyClientError;
+use crate::resource_clients::webhook_dispatch::{WebhookDispatch, WebhookDispatchStatus};
+use crate::resource_clients::webhook_dispatch_collection::WebhookDispatchCollectionClient;
+
+pub struct WebhookClient<'a> {
+    pub apify_client: &'a ApifyClient,
+    pub url_segment: String,
+}
+
+// See comment on the ResourceClient trait why this boilerplate is needed
+impl <'a> ResourceClient<'a, Webhook> for WebhookClient<'a> {
+    fn get_client(&self) -> &'a ApifyClient {
+        self.apify_client
+    }
+
+    fn get_url_segment(&self) -> &str {
+        &self.url_segment
+    }
+}
+
+impl <'a> WebhookClient<'a> {
+    pub fn new(apify_client: &'a ApifyClient, identifier: &str) -> Self {
+        WebhookClient {
+            apify_client,
+            url_segment: format!("webhooks/{}", identifier),
+        }
+    }
+
+    /// Only fields that are set on the definition are updated
+    pub fn update(&self, definition: WebhookDefinition) -> UpdateWebhookBuilder {
+        UpdateWebhookBuilder {
+            webhook_client: self,
+            payload: definition,
+        }
+    }
+
+    /// Dispatches the webhook with a dummy payload, returns the dispatch right away
+    pub fn test(&self) -> BaseBuilder<'a, WebhookDispatch> {
+        BaseBuilder::new(
+            self.apify_client,
+            format!("{}/test", self.url_segment),
+            Method::POST,
+        )
+    }
+
+    pub fn dispatches(&self) -> WebhookDispatchCollectionClient<'a> {
+        WebhookDispatchCollectionClient {
+            apify_client: self.apify_client,
+            url_segment: format!("{}/dispatches", self.url_segment),
+        }
+    }
+}
+
+pub struct UpdateWebhookBuilder<'a> {
+    webhook_client: &'a WebhookClient<'a>,
+    payload: WebhookDefinition,
+}
+
+impl <'a> UpdateWebhookBuilder<'a> {
+    pub async fn send(self) -> Result<Webhook, ApifyClientError> {
+        let mut builder: BaseBuilder<'_, Webhook> = BaseBuilder::new(
+            self.webhook_client.apify_client,
+            self.webhook_client.url_segment.clone(),
+            Method::PUT,
+        );
+        builder.raw_payload(serde_json::to_vec(&self.payload)?);
+        builder.headers(crate::utils::json_content_headers());
+        builder.send().await
+    }
+}
+
+/// Writable fields of a webhook
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookDefinition {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_types: Option<Vec<WebhookEventType>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub condition: Option<WebhookCondition>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_url: Option<String>,
+    /// JSON template with variables like `{{eventData}}` or `{{resource}}`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload_template: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ignore_ssl_errors: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub do_not_retry: Option<bool>,
+    /// Creating a webhook with an already used key returns the existing webhook
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub idempotency_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// Only one of the fields is set, it limits which runs or builds dispatch the webhook
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookCondition {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_task_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_run_id: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Webhook {
+    pub id: String,
+    pub user_id: String,
+    pub created_at: String,
+    pub modified_at: String,
+    pub is_ad_hoc: Option<bool>,
+    pub event_types: Vec<WebhookEventType>,
+    pub condition: WebhookCondition,
+    pub request_url: String,
+    pub payload_template: Option<String>,
+    #[serde(default)]
+    pub ignore_ssl_errors: bool,
+    #[serde(default)]
+    pub do_not_retry: bool,
+    pub description: Option<String>,
+    pub last_dispatch: Option<WebhookLastDispatch>,
+    #[serde(default)]
+    pub stats: WebhookStats,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookLastDispatch {
+    pub status: WebhookDispatchStatus,
+    pub finished_at: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct WebhookStats {
+    pub total_dispatches: u64,
+}
+
+/// Event that dispatches a webhook, event types added to the API later end up in `Unknown`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(from = "String", into = "String")]
+pub enum WebhookEventType {
+    ActorRunCreated,
+    ActorRunSucceeded,
+    ActorRunFailed,
+    ActorRunTimedOut,
+    ActorRunAborted,
+    ActorRunResurrected,
+    ActorBuildCreated,
+    ActorBuildSucceeded,
+    ActorBuildFailed,
+    ActorBuildTimedOut,
+    ActorBuildAborted,
+    Unknown(String),
+}
+
+impl From<String> for WebhookEventType {
+    fn from(event_type: String) -> Self {
+        match event_type.as_str() {
+            "ACTOR.RUN.CREATED" => WebhookEventType::ActorRunCreated,
+            "ACTOR.RUN.SUCCEEDED" => WebhookEventType::ActorRunSucceeded,
+            "ACTOR.RUN.FAILED" => WebhookEventType::ActorRunFailed,
+            "ACTOR.RUN.TIMED_OUT" => WebhookEventType::ActorRunTimedOut,
+            "ACTOR.RUN.ABORTED" => WebhookEventType::ActorRunAborted,
+            "ACTOR.RUN.RESURRECTED" => WebhookEventType::ActorRunResurrected,
+            "ACTOR.BUILD.CREATED" => WebhookEventType::ActorBuildCreated,
+            "ACTOR.BUILD.SUCCEEDED" => WebhookEventType::ActorBuildSucceeded,
+            "ACTOR.BUILD.FAILED" => WebhookEventType::ActorBuildFailed,
+            "ACTOR.BUILD.TIMED_OUT" => WebhookEventType::ActorBuildTimedOut,
+            "ACTOR.BUILD.ABORTED" => WebhookEventType::ActorBuildAborted,
+            _ => WebhookEventType::Unknown(event_type),
+        }
+    }
+}
+
+impl From<WebhookEventType> for String {
+    fn from(event_type: WebhookEventType) -> Self {
+        event_type.to_string()
+    }
+}
+
+impl std::fmt::Display for WebhookEventType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        let string_repr = match self {
+            WebhookEventType::ActorRunCreated => "ACTOR.RUN.CREATED",
+            WebhookEventType::ActorRunSucceeded => "ACTOR.RUN.SUCCEEDED",
+            WebhookEventType::ActorRunFailed => "ACTOR.RUN.FAILED",
+            WebhookEventType::ActorRunTimedOut => "ACTOR.RUN.TIMED_OUT",
+            WebhookEventType::ActorRunAborted => "ACTOR.RUN.ABORTED",
+            WebhookEventType::ActorRunResurrected => "ACTOR.RUN.RESURRECTED",
+            WebhookEventType::ActorBuildCreated => "ACTOR.BUILD.CREATED",
+            WebhookEventType::ActorBuildSucceeded => "ACTOR.BUILD.SUCCEEDED",
+            WebhookEventType::ActorBuildFailed => "ACTOR.BUILD.FAILED",
+            WebhookEventType::ActorBuildTimedOut => "ACTOR.BUILD.TIMED_OUT",
+            WebhookEventType::ActorBuildAborted => "ACTOR.BUILD.ABORTED",
+            WebhookEventType::Unknown(event_type) => event_type,
+        };
+        write!(f, "{}", string_repr)
+    }
+}
@@ -0,0 +1,57 @@
+use crate::apify_client::ApifyClient;
+use reqwest::Method;
+use crate::base_clients::resource_collection_client::ResourceCollectionClient;
+use crate::generic_types::BaseBuilder;
+use crate::error::ApifyClientError;
+use crate::resource_clients::webhook::{Webhook, WebhookDefinition};
+
+pub struct WebhookCollectionClient<'a> {
+    pub apify_client: &'a ApifyClient,
+    pub url_segment: String,
+}
+
+// See comment on the ResourceClient trait why this boilerplate is needed
+impl <'a> ResourceCollectionClient<'a, Webhook> for WebhookCollectionClient<'a> {
+    fn get_client(&self) -> &'a ApifyClient {
+        self.apify_client
+    }
+
+    fn get_url_segment(&self) -> &str {
+        &self.url_segment
+    }
+}
+
+impl <'a> WebhookCollectionClient<'a> {
+    pub fn new(apify_client: &'a ApifyClient) -> Self {
+        WebhookCollectionClient {
+            apify_client,
+            url_segment: "webhooks".to_owned(),
+        }
+    }
+
+    /// `event_types`, `condition` and `request_url` have to be set on the definition
+    pub fn create(&self, definition: WebhookDefinition) -> CreateWebhookBuilder {
+        CreateWebhookBuilder {
+            webhook_collection_client: self,
+            payload: definition,
+        }
+    }
+}
+
+pub struct CreateWebhookBuilder<'a> {
+    webhook_collection_client: &'a WebhookCollectionClient<'a>,
+    payload: WebhookDefinition,
+}
+
+impl <'a> CreateWebhookBuilder<'a> {
+    pub async fn send(self) -> Result<Webhook, ApifyClientError> {
+        let mut builder: BaseBuilder<'_, Webhook> = BaseBuilder::new(
+            self.webhook_collection_client.apify_client,
+            self.webhook_collection_client.url_segment.clone(),
+            Method::POST,
+        );
+        builder.raw_payload(serde_json::to_vec(&self.payload)?);
+        builder.headers(crate::utils::json_content_headers());
+        builder.send().await
+    }
+}
@@ -0,0 +1,95 @@
+use crate::apify_client::ApifyClient;
+use serde::{Deserialize, Serialize};
+use crate::base_clients::resource_client::ResourceClient;
+use crate::resource_clients::webhook::WebhookEventType;
+
+/// Single dispatch of a webhook with all its delivery attempts
+pub struct WebhookDispatchClient<'a> {
+    pub apify_client: &'a ApifyClient,
+    pub url_segment: String,
+}
+
+// See comment on the ResourceClient trait why this boilerplate is needed
+impl <'a> ResourceClient<'a, WebhookDispatch> for WebhookDispatchClient<'a> {
+    fn get_client(&self) -> &'a ApifyClient {
+        self.apify_client
+    }
+
+    fn get_url_segment(&self) -> &str {
+        &self.url_segment
+    }
+}
+
+impl <'a> WebhookDispatchClient<'a> {
+    pub fn new(apify_client: &'a ApifyClient, identifier: &str) -> Self {
+        WebhookDispatchClient {
+            apify_client,
+            url_segment: format!("webhook-dispatches/{}", identifier),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookDispatch {
+    pub id: String,
+    pub user_id: String,
+    pub webhook_id: String,
+    pub created_at: String,
+    pub status: WebhookDispatchStatus,
+    pub event_type: WebhookEventType,
+    pub event_data: Option<serde_json::Value>,
+    /// Missing until the first delivery attempt is made
+    #[serde(default)]
+    pub calls: Vec<WebhookDispatchCall>,
+}
+
+/// Delivery attempt of a dispatch
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookDispatchCall {
+    pub started_at: Option<String>,
+    pub finished_at: Option<String>,
+    pub error_message: Option<String>,
+    pub response_status: Option<u16>,
+    pub response_body: Option<String>,
+}
+
+/// Status of a dispatch, statuses added to the API later end up in `Unknown`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(from = "String", into = "String")]
+pub enum WebhookDispatchStatus {
+    Active,
+    Succeeded,
+    Failed,
+    Unknown(String),
+}
+
+impl From<String> for WebhookDispatchStatus {
+    fn from(status: String) -> Self {
+        match status.as_str() {
+            "ACTIVE" => WebhookDispatchStatus::Active,
+            "SUCCEEDED" => WebhookDispatchStatus::Succeeded,
+            "FAILED" => WebhookDispatchStatus::Failed,
+            _ => WebhookDispatchStatus::Unknown(status),
+        }
+    }
+}
+
+impl From<WebhookDispatchStatus> for String {
+    fn from(status: WebhookDispatchStatus) -> Self {
+        status.to_string()
+    }
+}
+
+impl std::fmt::Display for WebhookDispatchStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        let string_repr = match self {
+            WebhookDispatchStatus::Active => "ACTIVE",
+            WebhookDispatchStatus::Succeeded => "SUCCEEDED",
+            WebhookDispatchStatus::Failed => "FAILED",
+            WebhookDispatchStatus::Unknown(status) => status,
+        };
+        write!(f, "{}", string_repr)
+    }
+}
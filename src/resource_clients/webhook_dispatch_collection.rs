@@ -0,0 +1,29 @@
+use crate::apify_client::ApifyClient;
+use crate::base_clients::resource_collection_client::ResourceCollectionClient;
+use crate::resource_clients::webhook_dispatch::WebhookDispatch;
+
+/// Dispatches of the user or of a single webhook, depending on where it was created from
+pub struct WebhookDispatchCollectionClient<'a> {
+    pub apify_client: &'a ApifyClient,
+    pub url_segment: String,
+}
+
+// See comment on the ResourceClient trait why this boilerplate is needed
+impl <'a> ResourceCollectionClient<'a, WebhookDispatch> for WebhookDispatchCollectionClient<'a> {
+    fn get_client(&self) -> &'a ApifyClient {
+        self.apify_client
+    }
+
+    fn get_url_segment(&self) -> &str {
+        &self.url_segment
+    }
+}
+
+impl <'a> WebhookDispatchCollectionClient<'a> {
+    pub fn new(apify_client: &'a ApifyClient) -> Self {
+        WebhookDispatchCollectionClient {
+            apify_client,
+            url_segment: "webhook-dispatches".to_owned(),
+        }
+    }
+}
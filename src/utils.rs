@@ -1,6 +1,7 @@
-use crate::error::{ ApifyApiError };
-use reqwest::header::{HeaderMap, CONTENT_TYPE};
+use crate::error::{ ApifyApiError, ClientValidationError };
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
 use crate::generic_types::{IdOrName};
+use crate::resource_clients::run::RunStatus;
 
 #[derive(Debug)]
 pub enum ResourceType {
@@ -22,12 +23,42 @@ pub fn is_resource_by_name(id_or_name: &IdOrName) -> bool {
     return false;
 }
 
+// Status filter of the last run, empty if there is none so it can be passed to append_query_string
+pub fn run_status_query_string(status: &Option<RunStatus>) -> String {
+    match status {
+        Some(status) => format!("status={}", status),
+        None => String::new(),
+    }
+}
+
+// Query params are not encoded for us, free text like a search has to be
+pub fn encode_query_value(value: &str) -> String {
+    value.bytes().map(|byte| match byte {
+        b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (byte as char).to_string(),
+        _ => format!("%{:02X}", byte),
+    }).collect()
+}
+
+// Same chars are safe in a path segment, a '/' inside e.g. a record key has to be encoded too
+pub fn encode_path_segment(segment: &str) -> String {
+    encode_query_value(segment)
+}
+
 pub fn json_content_headers() -> HeaderMap {
     let mut headers = HeaderMap::new();
     headers.insert(CONTENT_TYPE, "application/json".parse().unwrap());
     headers
 }
 
+// Content type comes from the user so it can contain chars that are not allowed in a header
+pub fn content_type_headers(content_type: &str) -> Result<HeaderMap, ClientValidationError> {
+    let header_value = HeaderValue::from_str(content_type)
+        .map_err(|_| ClientValidationError::InvalidContentType(content_type.to_owned()))?;
+    let mut headers = HeaderMap::new();
+    headers.insert(CONTENT_TYPE, header_value);
+    Ok(headers)
+}
+
 pub fn parse_pagination_header(headers: &HeaderMap, header_name: &str) -> Result<u64, ApifyApiError> {
     headers
         .get(header_name)